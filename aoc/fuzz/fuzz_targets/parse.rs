@@ -0,0 +1,19 @@
+#![no_main]
+
+//! Feed arbitrary text to every day's parser: the first byte picks the day, the rest is the
+//! input. Parsers may reject it, but must never panic.
+
+use aoc::commands::{CommandImpl, Day};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&pick, input)) = data.split_first() else {
+        return;
+    };
+    let Ok(input) = std::str::from_utf8(input) else {
+        return;
+    };
+    let ids = Day::ids();
+    let day = Day::from_id(ids[usize::from(pick) % ids.len()]).unwrap();
+    let _ = day.check_input(input);
+});
@@ -0,0 +1,156 @@
+//! The session behind `aoc repl`: a day's input is parsed once, and then each line read is a
+//! command run against what was parsed, such as solving a part or one the day offers itself.
+
+use std::fmt;
+use std::io::{BufRead, Write};
+use std::str::FromStr;
+use std::time::Instant;
+
+use super::{DynError, Solution, Solver};
+use crate::tasks::guarded;
+
+/// Commands a day offers in the REPL for poking at its parsed input, beyond solving its parts.
+pub trait Explore {
+    /// The usage and a one-line description of every command, e.g. `("step [n]", "...")`. The
+    /// first word of the usage is the command's name.
+    fn commands(&self) -> &'static [(&'static str, &'static str)];
+
+    /// Run the command called `command`, one of [`Explore::commands`], and return what to print.
+    fn exec(&mut self, command: &str, args: &[&str]) -> Result<String, DynError>;
+}
+
+/// The commands every day has.
+const BUILTIN: &[(&str, &str)] = &[
+    ("part1", "solve part 1"),
+    ("part2", "solve part 2"),
+    ("help", "list the commands"),
+    ("quit", "leave the REPL"),
+];
+
+/// The argument at `index`, called `name` in error messages.
+pub fn arg<T>(args: &[&str], index: usize, name: &str) -> Result<T, DynError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let value = args.get(index).ok_or_else(|| format!("missing {name}"))?;
+    value.parse().map_err(|e| format!("bad {name} {value:?}: {e}").into())
+}
+
+/// The argument at `index` like [`arg`], or `default` when it is not given.
+pub fn arg_or<T>(args: &[&str], index: usize, name: &str, default: T) -> Result<T, DynError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    if index < args.len() {
+        arg(args, index, name)
+    } else {
+        Ok(default)
+    }
+}
+
+fn help(explorer: Option<&dyn Explore>) -> String {
+    let commands: Vec<&(&str, &str)> =
+        BUILTIN.iter().chain(explorer.map_or(&[][..], |e| e.commands())).collect();
+    let width = commands.iter().map(|(usage, _)| usage.len()).max().unwrap_or_default();
+    let lines: Vec<String> =
+        commands.iter().map(|(usage, about)| format!("{usage:<width$}  {about}")).collect();
+    lines.join("\n")
+}
+
+fn offers(explorer: &dyn Explore, command: &str) -> bool {
+    explorer.commands().iter().any(|(usage, _)| usage.split_whitespace().next() == Some(command))
+}
+
+fn solve(part: impl FnOnce() -> Result<Solution, DynError>) -> Result<String, DynError> {
+    let start = Instant::now();
+    let solution = guarded(part)?;
+    Ok(format!("{solution} ({:.2?})", start.elapsed()))
+}
+
+/// Read commands from `commands` and write what each gives to `out`, writing `prompt` before
+/// each one, until `quit` or the end of `commands`. A command that fails or panics is reported
+/// and the session goes on.
+pub fn session<S: Solver + ?Sized>(
+    solver: &S,
+    parsed: &S::Parsed,
+    commands: &mut dyn BufRead,
+    out: &mut dyn Write,
+    prompt: &str,
+) -> Result<(), DynError> {
+    let mut explorer = solver.explore(parsed);
+    let mut line = String::new();
+    loop {
+        write!(out, "{prompt}")?;
+        out.flush()?;
+        line.clear();
+        if commands.read_line(&mut line)? == 0 {
+            return Ok(());
+        }
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let Some((&command, args)) = words.split_first() else {
+            continue;
+        };
+        let result = match command {
+            "quit" | "exit" => return Ok(()),
+            "help" => Ok(help(explorer.as_deref())),
+            "part1" => solve(|| solver.part1(parsed)),
+            "part2" => solve(|| solver.part2(parsed)),
+            _ => match explorer.as_deref_mut() {
+                Some(explorer) if offers(explorer, command) => {
+                    guarded(|| explorer.exec(command, args)).map_err(DynError::from)
+                }
+                _ => Err(format!("unknown command {command:?}, try help").into()),
+            },
+        };
+        match result {
+            Ok(text) => writeln!(out, "{text}")?,
+            Err(e) => writeln!(out, "error: {e}")?,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_arg() -> Result<(), Box<dyn std::error::Error>> {
+        let args = ["3", "x"];
+        assert_eq!(arg::<usize>(&args, 0, "row")?, 3);
+        assert_eq!(
+            arg::<usize>(&args, 1, "column").unwrap_err().to_string(),
+            "bad column \"x\": invalid digit found in string"
+        );
+        assert_eq!(arg::<usize>(&args, 2, "count").unwrap_err().to_string(), "missing count");
+        assert_eq!(arg_or(&args, 2, "count", 1_usize)?, 1);
+        assert!(arg_or(&args, 1, "count", 1_usize).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_session() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::commands::{CommandImpl, Day, PuzzleId};
+
+        let day = Day::from_id(PuzzleId::new(2024, 10))?;
+        let map = "0123\n1234\n8765\n9876\n";
+        let mut out = Vec::new();
+        let commands = "part1\n\nscore 0 0\nscore 9 9\nbogus\nquit\npart2\n";
+        day.repl(map, &mut commands.as_bytes(), &mut out, "> ")?;
+
+        let out = String::from_utf8(out)?;
+        let lines: Vec<&str> = out.lines().collect();
+        assert!(lines[0].starts_with("> part 1: 1 ("), "{out}");
+        assert_eq!(
+            lines[1..],
+            [
+                "> > 1",
+                "> error: (9, 9) is off the map",
+                "> error: unknown command \"bogus\", try help",
+                "> "
+            ]
+        );
+        Ok(())
+    }
+}
@@ -1,40 +1,636 @@
-pub mod day0;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
-pub mod day17;
-pub mod day18;
-pub mod day19;
-pub mod day1a;
-pub mod day1b;
-pub mod day20;
-pub mod day21;
-pub mod day22;
-pub mod day23;
-pub mod day24;
-pub mod day25;
-pub mod day2a;
-pub mod day3a;
-pub mod day4a;
-pub mod day4b;
-pub mod day5a;
-pub mod day5b;
-pub mod day6a;
-pub mod day7a;
-pub mod day9a;
-pub mod day9b;
+pub mod explore;
+pub mod y2022;
+pub mod y2024;
 
+use std::env;
 use std::error::Error;
+use std::ffi::OsString;
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, Read, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
 
-use enum_dispatch::enum_dispatch;
+use clap::error::ErrorKind;
+use clap::{ArgMatches, Args, FromArgMatches, Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+
+use self::explore::Explore;
+use crate::memory::{self, Memory};
+use crate::utils::random::{self, InputRng};
+use crate::utils::{AocError, ParseError};
 
 pub type DynError = Box<dyn Error + 'static>;
 
-#[enum_dispatch]
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+/// Numbers too big for an `i64` are kept as text rather than wrapping around.
+macro_rules! impl_number_answer {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                i64::try_from(n).map_or_else(|_| Answer::Text(n.to_string()), Answer::Number)
+            }
+        })*
+    };
+}
+
+impl_number_answer!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+/// The answers a day produced, one per part, plus any notes worth reporting alongside them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Solution {
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+    pub diagnostics: Vec<String>,
+}
+
+impl Solution {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_part1<A: Into<Answer>>(mut self, answer: A) -> Self {
+        self.part1 = Some(answer.into());
+        self
+    }
+
+    pub fn with_part2<A: Into<Answer>>(mut self, answer: A) -> Self {
+        self.part2 = Some(answer.into());
+        self
+    }
+
+    pub fn with_diagnostic<S: Into<String>>(mut self, diagnostic: S) -> Self {
+        self.diagnostics.push(diagnostic.into());
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.part1.is_none() && self.part2.is_none()
+    }
+
+    /// Fold the answers and diagnostics of `other` into this solution.
+    pub fn merge(mut self, other: Solution) -> Self {
+        self.part1 = other.part1.or(self.part1);
+        self.part2 = other.part2.or(self.part2);
+        self.diagnostics.extend(other.diagnostics);
+        self
+    }
+}
+
+impl fmt::Display for Solution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "no answer");
+        }
+        let parts = [("part 1", &self.part1), ("part 2", &self.part2)];
+        let answers: Vec<String> = parts
+            .iter()
+            .filter_map(|(name, answer)| answer.as_ref().map(|a| format!("{name}: {a}")))
+            .collect();
+        write!(f, "{}", answers.join("\n"))
+    }
+}
+
+/// Which parts of a puzzle to solve.
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Parts {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    #[default]
+    Both,
+}
+
+impl Parts {
+    pub fn part1(self) -> bool {
+        self != Parts::Two
+    }
+
+    pub fn part2(self) -> bool {
+        self != Parts::One
+    }
+}
+
+/// The options shared by every day subcommand.
+#[derive(Args, Debug, Clone, Default)]
+pub struct DayArgs {
+    /// Read the puzzle input from this file, or from stdin when it is `-`.
+    #[clap(long, short)]
+    pub input: Option<PathBuf>,
+    /// Solve only part 1, only part 2, or both.
+    #[clap(long, short, value_enum, default_value_t = Parts::Both)]
+    pub part: Parts,
+}
+
+/// How long each phase of a day took.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+/// The solution of a day together with its timings.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Run {
+    pub solution: Solution,
+    pub timings: Timings,
+    /// What each phase allocated, all zero unless `--mem-stats` turned counting on.
+    pub memory: Memory,
+}
+
+/// Environment variable naming the directory that default inputs are read from.
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+/// The directory holding one `<year>/day<N>.txt` input per day: `$AOC_INPUT_DIR`, or
+/// `aoc/inputs`.
+pub fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_ENV).map_or_else(|| PathBuf::from("aoc/inputs"), PathBuf::from)
+}
+
+/// Where the input of the puzzle `id` is kept in `dir`: `<dir>/<year>/<input>.txt`, named after
+/// the day's [`Puzzle::input`], or `day<N>` for a day that is not registered.
+pub fn input_in(dir: &Path, id: PuzzleId) -> PathBuf {
+    let name = match Registration::find(id) {
+        Some(registration) => registration.puzzle.input.to_string(),
+        None => format!("day{}", id.day),
+    };
+    dir.join(id.year.to_string()).join(format!("{name}.txt"))
+}
+
+/// Where the input of the puzzle `id` is found when `--input` is not given.
+pub fn default_input(id: PuzzleId) -> PathBuf {
+    input_in(&input_dir(), id)
+}
+
+/// Read puzzle input from `path`, or from stdin when `path` is `-`.
+pub fn read_input(path: &Path) -> Result<String, AocError> {
+    let io_error = |source| AocError::Io { path: path.to_path_buf(), source };
+    if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input).map_err(io_error)?;
+        return Ok(input);
+    }
+    fs::read_to_string(path).map_err(io_error)
+}
+
+/// A day split into a parse phase and one solve phase per part, so that each can be timed on its
+/// own and both parts share the parsed input.
+///
+/// Each part returns a [`Solution`] holding its own answer; a part that has not been solved yet
+/// keeps the default and reports no answer.
+pub trait Solver {
+    type Parsed;
+
+    fn args(&self) -> &DayArgs;
+    fn parse(&self, input: &str) -> Result<Self::Parsed, DynError>;
+
+    fn part1(&self, _parsed: &Self::Parsed) -> Result<Solution, DynError> {
+        Ok(Solution::new())
+    }
+
+    fn part2(&self, _parsed: &Self::Parsed) -> Result<Solution, DynError> {
+        Ok(Solution::new())
+    }
+
+    /// A random, well-formed input about `size` big, or about as big as a real input when `size`
+    /// is not given. Days that cannot make up inputs return `None`.
+    fn generate(&self, _rng: &mut InputRng, _size: Option<usize>) -> Option<String> {
+        None
+    }
+
+    /// The commands `aoc repl` offers for exploring `parsed`, beyond solving the parts. Days with
+    /// nothing more to show return `None`.
+    fn explore<'a>(&self, _parsed: &'a Self::Parsed) -> Option<Box<dyn Explore + 'a>> {
+        None
+    }
+}
+
 pub trait CommandImpl {
-    fn main(&self) -> Result<(), DynError>;
+    fn args(&self) -> &DayArgs;
+    fn run_parts(&self, input: &str, parts: Parts) -> Result<Run, DynError>;
+
+    /// Parse `input` without solving anything, to check that it is well formed.
+    fn check_input(&self, input: &str) -> Result<(), DynError>;
+
+    /// The random input `seed` gives for this day, or `None` if it has no generator.
+    fn generate_input(&self, seed: u64, size: Option<usize>) -> Option<String>;
+
+    /// Parse `input` once, then run the REPL commands read from `commands` against it; see
+    /// [`explore::session`].
+    fn repl(
+        &self,
+        input: &str,
+        commands: &mut dyn BufRead,
+        out: &mut dyn Write,
+        prompt: &str,
+    ) -> Result<(), DynError>;
+
+    /// Solve the parts selected with `--part`.
+    fn run(&self, input: &str) -> Result<Run, DynError> {
+        self.run_parts(input, self.args().part)
+    }
+}
+
+impl<S: Solver> CommandImpl for S {
+    fn args(&self) -> &DayArgs {
+        Solver::args(self)
+    }
+
+    fn run_parts(&self, input: &str, parts: Parts) -> Result<Run, DynError> {
+        let start = Instant::now();
+        let (parsed, parse_memory) = memory::measure(|| self.parse(input));
+        let parsed = parsed?;
+        let parse = start.elapsed();
+
+        let start = Instant::now();
+        let (solution, solve_memory) = memory::measure(|| -> Result<Solution, DynError> {
+            let mut solution = Solution::new();
+            if parts.part1() {
+                solution = solution.merge(self.part1(&parsed)?);
+            }
+            if parts.part2() {
+                solution = solution.merge(self.part2(&parsed)?);
+            }
+            Ok(solution)
+        });
+        let solution = solution?;
+        let solve = start.elapsed();
+
+        Ok(Run {
+            solution,
+            timings: Timings { parse, solve },
+            memory: Memory { parse: parse_memory, solve: solve_memory },
+        })
+    }
+
+    fn check_input(&self, input: &str) -> Result<(), DynError> {
+        self.parse(input).map(|_| ())
+    }
+
+    fn generate_input(&self, seed: u64, size: Option<usize>) -> Option<String> {
+        self.generate(&mut random::seeded(seed), size)
+    }
+
+    fn repl(
+        &self,
+        input: &str,
+        commands: &mut dyn BufRead,
+        out: &mut dyn Write,
+        prompt: &str,
+    ) -> Result<(), DynError> {
+        explore::session(self, &self.parse(input)?, commands, out, prompt)
+    }
+}
+
+/// Names one puzzle among every event, written `2024/day16`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PuzzleId {
+    pub year: u16,
+    pub day: u8,
+}
+
+impl PuzzleId {
+    pub const fn new(year: u16, day: u8) -> Self {
+        Self { year, day }
+    }
+}
+
+impl fmt::Display for PuzzleId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/day{}", self.year, self.day)
+    }
+}
+
+impl FromStr for PuzzleId {
+    type Err = ParseError;
+
+    /// Read `2024/day16`, or just `2024/16`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseError::new(format!("{s:?} is not a puzzle like 2024/day16"));
+        let (year, day) = s.split_once('/').ok_or_else(invalid)?;
+        let day = day.strip_prefix("day").unwrap_or(day);
+        Ok(Self::new(year.parse().map_err(|_| invalid())?, day.parse().map_err(|_| invalid())?))
+    }
+}
+
+/// The puzzle a day solves, declared next to its solver when it is registered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    /// The parts the solver answers.
+    pub parts: Parts,
+    /// The name of the default input in the year's input directory, without `.txt`.
+    pub input: &'static str,
+}
+
+impl Puzzle {
+    pub fn id(&self) -> PuzzleId {
+        PuzzleId::new(self.year, self.day)
+    }
+}
+
+/// A day in the registry: its puzzle, the subcommand that runs it, and how to build it.
+///
+/// Each day registers itself in its own module with [`inventory::submit!`], so the command line
+/// and every task pick it up without a list of days to keep up to date. Days are keyed by year
+/// and day, and run as `aoc <year> day<N>`.
+pub struct Registration {
+    pub puzzle: Puzzle,
+    command: fn() -> clap::Command,
+    build: fn(&ArgMatches) -> Result<Box<dyn CommandImpl>, clap::Error>,
+}
+
+inventory::collect!(Registration);
+
+fn build<S>(matches: &ArgMatches) -> Result<Box<dyn CommandImpl>, clap::Error>
+where
+    S: Solver + FromArgMatches + 'static,
+{
+    Ok(Box::new(S::from_arg_matches(matches)?))
+}
+
+impl Registration {
+    /// Register the solver `S` for `puzzle`.
+    pub const fn new<S>(puzzle: Puzzle) -> Self
+    where
+        S: Solver + Parser + 'static,
+    {
+        Self { puzzle, command: S::command, build: build::<S> }
+    }
+
+    /// Every registered day, by year and then by day.
+    pub fn all() -> &'static [&'static Registration] {
+        static ALL: OnceLock<Vec<&'static Registration>> = OnceLock::new();
+        ALL.get_or_init(|| {
+            let mut all: Vec<&'static Registration> = inventory::iter::<Registration>().collect();
+            all.sort_by_key(|registration| registration.puzzle.id());
+            all
+        })
+    }
+
+    /// The day registered for the puzzle `id`.
+    pub fn find(id: PuzzleId) -> Option<&'static Registration> {
+        Self::all().iter().copied().find(|registration| registration.puzzle.id() == id)
+    }
+
+    /// Every year with a registered day, in order.
+    pub fn years() -> Vec<u16> {
+        let mut years: Vec<u16> = Self::all().iter().map(|r| r.puzzle.year).collect();
+        years.dedup();
+        years
+    }
+
+    fn subcommand(&self) -> clap::Command {
+        (self.command)().name(format!("day{}", self.puzzle.day)).about(self.puzzle.title)
+    }
+}
+
+/// A registered day, built from the arguments of its subcommand.
+pub struct Day {
+    registration: &'static Registration,
+    command: Box<dyn CommandImpl>,
+}
+
+impl fmt::Debug for Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Day").field("id", &self.id()).field("args", self.args()).finish()
+    }
+}
+
+impl CommandImpl for Day {
+    fn args(&self) -> &DayArgs {
+        self.command.args()
+    }
+
+    fn run_parts(&self, input: &str, parts: Parts) -> Result<Run, DynError> {
+        self.command.run_parts(input, parts)
+    }
+
+    fn check_input(&self, input: &str) -> Result<(), DynError> {
+        self.command.check_input(input)
+    }
+
+    fn generate_input(&self, seed: u64, size: Option<usize>) -> Option<String> {
+        self.command.generate_input(seed, size)
+    }
+
+    fn repl(
+        &self,
+        input: &str,
+        commands: &mut dyn BufRead,
+        out: &mut dyn Write,
+        prompt: &str,
+    ) -> Result<(), DynError> {
+        self.command.repl(input, commands, out, prompt)
+    }
+}
+
+impl FromArgMatches for Day {
+    fn from_arg_matches(matches: &ArgMatches) -> Result<Self, clap::Error> {
+        let missing = |what: &str| clap::Error::raw(ErrorKind::MissingSubcommand, what);
+        let (year, matches) = matches.subcommand().ok_or_else(|| missing("no year given\n"))?;
+        let (day, matches) = matches.subcommand().ok_or_else(|| missing("no day given\n"))?;
+        let registration =
+            format!("{year}/{day}").parse().ok().and_then(Registration::find).ok_or_else(|| {
+                let message = format!("no solver for {year} {day}\n");
+                clap::Error::raw(ErrorKind::InvalidSubcommand, message)
+            })?;
+        Ok(Self { registration, command: (registration.build)(matches)? })
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &ArgMatches) -> Result<(), clap::Error> {
+        *self = Self::from_arg_matches(matches)?;
+        Ok(())
+    }
+}
+
+/// One subcommand per year, holding one subcommand per registered day of that year.
+impl Subcommand for Day {
+    fn augment_subcommands(command: clap::Command) -> clap::Command {
+        command.subcommands(Registration::years().into_iter().map(|year| {
+            let days = Registration::all().iter().filter(|r| r.puzzle.year == year);
+            clap::Command::new(year.to_string())
+                .about(format!("Solve a puzzle from Advent of Code {year}"))
+                .subcommand_required(true)
+                .subcommands(days.map(|registration| registration.subcommand()))
+        }))
+    }
+
+    fn augment_subcommands_for_update(command: clap::Command) -> clap::Command {
+        Self::augment_subcommands(command)
+    }
+
+    fn has_subcommand(name: &str) -> bool {
+        name.parse().is_ok_and(|year| Registration::years().contains(&year))
+    }
+}
+
+impl Day {
+    /// The puzzle of every registered day, in the order of [`Registration::all`].
+    pub fn ids() -> &'static [PuzzleId] {
+        static IDS: OnceLock<Vec<PuzzleId>> = OnceLock::new();
+        IDS.get_or_init(|| Registration::all().iter().map(|r| r.puzzle.id()).collect())
+    }
+
+    /// Build the day solving `id`, reading its default input when run through [`Day::main`].
+    pub fn from_id(id: PuzzleId) -> Result<Self, DynError> {
+        Self::from_args(Self::args_for(id))
+    }
+
+    /// Build the day solving `id` as if it had been invoked with `--input input`.
+    pub fn with_input(id: PuzzleId, input: &Path) -> Result<Self, DynError> {
+        let mut args = Self::args_for(id);
+        args.extend(["--input".into(), input.into()]);
+        Self::from_args(args)
+    }
+
+    /// Build the day solving `id` as if it had been invoked with the day's own options `args`,
+    /// such as `["--size", "7"]`.
+    pub fn with_args<I, T>(id: PuzzleId, args: I) -> Result<Self, DynError>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString>,
+    {
+        let mut all = Self::args_for(id);
+        all.extend(args.into_iter().map(Into::into));
+        Self::from_args(all)
+    }
+
+    fn args_for(id: PuzzleId) -> Vec<OsString> {
+        vec!["aoc".into(), id.year.to_string().into(), format!("day{}", id.day).into()]
+    }
+
+    fn from_args(args: Vec<OsString>) -> Result<Self, DynError> {
+        let command = Self::augment_subcommands(clap::Command::new("aoc"));
+        let matches = command.try_get_matches_from(args)?;
+        Ok(Self::from_arg_matches(&matches)?)
+    }
+
+    pub fn id(&self) -> PuzzleId {
+        self.registration.puzzle.id()
+    }
+
+    pub fn puzzle(&self) -> &'static Puzzle {
+        &self.registration.puzzle
+    }
+
+    /// The input this day reads: `--input` when given, otherwise its default path.
+    pub fn input_path(&self) -> PathBuf {
+        self.args().input.clone().unwrap_or_else(|| default_input(self.id()))
+    }
+
+    /// Read this day's input and solve it.
+    pub fn main(&self) -> Result<Run, DynError> {
+        let input = read_input(&self.input_path())?;
+        self.run(&input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_every_module_is_registered() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src").join("commands");
+        for year in fs::read_dir(dir)? {
+            let year = year?.path();
+            if !year.is_dir() {
+                continue;
+            }
+            for entry in fs::read_dir(&year)? {
+                let path = entry?.path();
+                let module = path.strip_prefix(&year)?.with_extension("");
+                let module = module.to_string_lossy();
+                if module == "mod" {
+                    continue;
+                }
+                let year = year.file_name().unwrap_or_default().to_string_lossy();
+                let id = format!("{}/{module}", year.trim_start_matches('y')).parse::<PuzzleId>();
+                assert!(
+                    id.is_ok_and(|id| Registration::find(id).is_some()),
+                    "{}/{module} is not registered as that year and day",
+                    year
+                );
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_registrations_are_distinct() {
+        let mut ids = HashSet::new();
+        for registration in Registration::all() {
+            let Puzzle { year, day, title, .. } = registration.puzzle;
+            assert!(ids.insert(registration.puzzle.id()), "{year} day {day} is registered twice");
+            assert!(year >= 2015 && (1..=25).contains(&day), "{year} day {day} is not a puzzle");
+            assert!(!title.is_empty(), "{year} day {day} has no title");
+        }
+    }
+
+    #[test]
+    fn test_answer_from_number() {
+        assert_eq!(Answer::from(-3_i32), Answer::Number(-3));
+        assert_eq!(Answer::from(i64::MAX as u64), Answer::Number(i64::MAX));
+        assert_eq!(Answer::from(u64::MAX), Answer::Text("18446744073709551615".to_string()));
+        assert_eq!(Answer::from(u64::MAX).to_string(), u64::MAX.to_string());
+    }
+
+    #[test]
+    fn test_puzzle_id() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!("2024/day16".parse::<PuzzleId>()?, PuzzleId::new(2024, 16));
+        assert_eq!("2022/1".parse::<PuzzleId>()?, PuzzleId::new(2022, 1));
+        assert_eq!(PuzzleId::new(2022, 1).to_string(), "2022/day1");
+        assert!("day16".parse::<PuzzleId>().is_err());
+        assert!("2024/dayx".parse::<PuzzleId>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_day_from_registry() -> Result<(), Box<dyn std::error::Error>> {
+        let calories = PuzzleId::new(2022, 1);
+        let day = Day::with_input(calories, Path::new("calories.txt"))?;
+        assert_eq!(day.id(), calories);
+        assert_eq!(day.puzzle().title, "Calorie Counting");
+        assert_eq!(day.input_path(), PathBuf::from("calories.txt"));
+        assert_eq!(Day::from_id(calories)?.input_path(), input_dir().join("2022").join("day1.txt"));
+        assert_eq!(Day::from_id(PuzzleId::new(2024, 1))?.puzzle().title, "Historian Hysteria");
+        assert!(Day::from_id(PuzzleId::new(2024, 26)).is_err());
+        assert!(Day::from_id(PuzzleId::new(1999, 1)).is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,146 @@
+use clap::Parser;
+
+use crate::utils::{slurp_str, AocError, SlurpError};
+use rand::Rng;
+
+use crate::commands::{DayArgs, DynError, Parts, Puzzle, Registration, Solution, Solver};
+use crate::utils::random::InputRng;
+
+/// Find the elf with the most calories in their pack.
+#[derive(Parser, Debug, Default)]
+pub struct Day1 {
+    #[clap(flatten)]
+    args: DayArgs,
+}
+
+inventory::submit! {
+    Registration::new::<Day1>(Puzzle {
+        year: 2022,
+        day: 1,
+        title: "Calorie Counting",
+        parts: Parts::Two,
+        input: "day1",
+    })
+}
+
+impl Solver for Day1 {
+    type Parsed = Vec<Elf>;
+
+    fn args(&self) -> &DayArgs {
+        &self.args
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, DynError> {
+        let lines: Vec<String> = slurp_str(input)?;
+
+        let mut elves = vec![];
+        let mut current_elf = Elf::new();
+
+        for (i, line) in lines.iter().enumerate() {
+            if line.is_empty() {
+                elves.push(current_elf);
+                current_elf = Elf::new();
+            } else {
+                let snack = line.parse::<usize>().map_err(|e| {
+                    // Point at the first character that is not a digit, if there is one.
+                    let column = line.find(|c: char| !c.is_ascii_digit()).map_or(1, |j| j + 1);
+                    AocError::from(SlurpError::new(i + 1, column, e.to_string()))
+                })?;
+                current_elf.add_snack(snack);
+            }
+        }
+        // The last elf has no blank line after their pack.
+        if !current_elf.snacks.is_empty() {
+            elves.push(current_elf);
+        }
+        Ok(elves)
+    }
+
+    fn part2(&self, elves: &Self::Parsed) -> Result<Solution, DynError> {
+        let mut elves = elves.clone();
+
+        // Note that we could use `elves.sort_by` here, but this is a good chance to look at the PartialOrd and Ord traits
+        elves.sort_unstable();
+
+        let top_three: usize = elves.iter().rev().map(|e| e.total_calories).take(3).sum();
+
+        Ok(Solution::new().with_part2(top_three))
+    }
+
+    fn generate(&self, rng: &mut InputRng, size: Option<usize>) -> Option<String> {
+        let elves: Vec<String> = (0..size.unwrap_or(250).max(1))
+            .map(|_| {
+                let nsnacks = rng.gen_range(1..=15);
+                (0..nsnacks).map(|_| format!("{}\n", rng.gen_range(1000..60000))).collect()
+            })
+            .collect();
+        Some(elves.join("\n"))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Elf {
+    /// List of snacks for this elf, represented by the calories of each snack
+    snacks: Vec<usize>,
+    /// Sum of snacks
+    total_calories: usize,
+}
+
+impl Elf {
+    pub fn new() -> Self {
+        Self { snacks: vec![], total_calories: 0 }
+    }
+
+    pub fn add_snack(&mut self, snack: usize) {
+        self.snacks.push(snack);
+        self.total_calories += snack;
+    }
+}
+
+impl Default for Elf {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PartialOrd for Elf {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Elf {
+    fn eq(&self, other: &Self) -> bool {
+        self.total_calories == other.total_calories
+    }
+}
+
+impl Ord for Elf {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.total_calories.cmp(&other.total_calories)
+    }
+}
+impl Eq for Elf {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part2_counts_the_last_elf() -> Result<(), Box<dyn std::error::Error>> {
+        let day = Day1::default();
+        let elves = day.parse("1000\n\n2000\n\n3000\n4000\n")?;
+        assert_eq!(elves.len(), 3);
+        assert_eq!(day.part2(&elves)?.part2, Some(10000.into()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_error_position() {
+        let error = Day1::default().parse("1000\n2000\n\n3x00\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "parse error at line 4, column 2: invalid digit found in string"
+        );
+    }
+}
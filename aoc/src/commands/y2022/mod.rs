@@ -0,0 +1,3 @@
+//! Solutions to the puzzles of Advent of Code 2022.
+
+pub mod day1;
@@ -0,0 +1,93 @@
+use clap::Parser;
+use log::debug;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::utils::AocError;
+use std::str::FromStr;
+
+use crate::commands::{DayArgs, DynError, Parts, Puzzle, Registration, Solution, Solver};
+use crate::utils::random::InputRng;
+use std::collections::HashMap;
+
+/// Compare two lists of location IDs, by the distance between them once sorted and by how often
+/// each left ID turns up on the right.
+#[derive(Parser, Debug, Default)]
+pub struct Day1 {
+    #[clap(flatten)]
+    args: DayArgs,
+}
+
+inventory::submit! {
+    Registration::new::<Day1>(Puzzle {
+        year: 2024,
+        day: 1,
+        title: "Historian Hysteria",
+        parts: Parts::Both,
+        input: "day1",
+    })
+}
+
+impl Solver for Day1 {
+    type Parsed = Vec<(u32, u32)>;
+
+    fn args(&self) -> &DayArgs {
+        &self.args
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, DynError> {
+        let number =
+            |part: &str| u32::from_str(part).map_err(|_| AocError::at(input, part, "number"));
+        input
+            .lines()
+            .map(|line| {
+                let (left, right) = line
+                    .split_once("   ")
+                    .ok_or_else(|| AocError::at(input, line, "two numbers three spaces apart"))?;
+                Ok((number(left)?, number(right)?))
+            })
+            .collect()
+    }
+
+    fn part1(&self, x: &Self::Parsed) -> Result<Solution, DynError> {
+        let mut left: Vec<u32> = x.iter().map(|x| x.0).collect();
+        let mut right: Vec<u32> = x.iter().map(|x| x.1).collect();
+        left.sort();
+        right.sort();
+        let sum_diffs: u32 = std::iter::zip(left, right).map(|x| x.0.abs_diff(x.1)).sum();
+        Ok(Solution::new().with_part1(sum_diffs))
+    }
+
+    fn part2(&self, x: &Self::Parsed) -> Result<Solution, DynError> {
+        let mut left: Vec<u32> = x.iter().map(|x| x.0).collect();
+        debug!("x is {:?}", x);
+        let mut right: Vec<u32> = x.iter().map(|x| x.1).collect();
+        left.sort();
+        right.sort();
+        debug!("left is {:?}", left);
+        debug!("right is {:?}", right);
+        let mut map: HashMap<u32, u32> = HashMap::new();
+        for key in right {
+            map.entry(key).or_insert(0);
+            let count = map.get(&key).unwrap();
+            map.insert(key, count + 1);
+        }
+        let part_b_sum: u32 = left.iter().filter(|x| map.contains_key(x)).map(|x| x * map[x]).sum();
+        Ok(Solution::new().with_part2(part_b_sum))
+    }
+
+    fn generate(&self, rng: &mut InputRng, size: Option<usize>) -> Option<String> {
+        let left: Vec<u32> =
+            (0..size.unwrap_or(1000)).map(|_| rng.gen_range(10000..100000)).collect();
+        let mut input = String::new();
+        for l in left.iter() {
+            // Reuse left-hand numbers on the right now and then, so part two finds some matches.
+            let r: u32 = match left.choose(rng) {
+                Some(&r) if rng.gen_bool(0.3) => r,
+                _ => rng.gen_range(10000..100000),
+            };
+            input.push_str(&format!("{l}   {r}\n"));
+        }
+        Some(input)
+    }
+}
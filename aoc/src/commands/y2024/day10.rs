@@ -0,0 +1,186 @@
+use clap::Parser;
+use log::debug;
+use rand::Rng;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+use crate::commands::explore::{arg, Explore};
+use crate::commands::{DayArgs, DynError, Parts, Puzzle, Registration, Solution, Solver};
+use crate::utils::grid::Grid;
+use crate::utils::random::InputRng;
+
+//use nom::sequence::preceded;
+
+#[derive(Parser, Debug, Default)]
+pub struct Day10 {
+    #[clap(flatten)]
+    args: DayArgs,
+}
+
+inventory::submit! {
+    Registration::new::<Day10>(Puzzle {
+        year: 2024,
+        day: 10,
+        title: "Hoof It",
+        parts: Parts::Both,
+        input: "day10",
+    })
+}
+
+#[derive(Debug)]
+pub struct TopographicMap {
+    trail_map: Grid<u32>,
+}
+
+impl TopographicMap {
+    pub fn new(trail_map: Grid<u32>) -> Self {
+        Self { trail_map }
+    }
+}
+
+pub type Point = (usize, usize);
+impl TopographicMap {
+    pub fn find_trailheads(&self) -> Vec<Point> {
+        self.trail_map.find_all(&0).collect()
+    }
+
+    pub fn trails(&self) -> HashSet<Point> {
+        self.trail_map.iter().filter(|&(_, &val)| val != 0).map(|(p, _)| p).collect()
+    }
+
+    pub fn count_all_trailheads(&self) -> usize {
+        let mut candidate_trailhead: Vec<Point> = self.find_trailheads();
+        let mut trailhead_count: Vec<usize> = Vec::new();
+        while let Some(trailhead) = candidate_trailhead.pop() {
+            trailhead_count.push(self.count_distinct_trails(trailhead));
+        }
+        trailhead_count.iter().sum()
+    }
+
+    pub fn score_all_trailheads(&self) -> usize {
+        self.find_trailheads().into_iter().map(|p| self.count_trailheads(p)).sum()
+    }
+
+    pub fn count_trailheads(&self, p: Point) -> usize {
+        let mut trailheads: VecDeque<Point> = VecDeque::new();
+        let mut visited: HashSet<Point> = HashSet::new();
+        trailheads.push_back(p);
+        let trails: HashSet<Point> = self.trails();
+        let mut ntrails: usize = 0;
+        while let Some(p) = trailheads.pop_front() {
+            let value: u32 = self.trail_map[p];
+            if value == 9 {
+                ntrails += 1;
+            }
+            for point in self.trail_map.neighbors4(p) {
+                if trails.contains(&point)
+                    && self.trail_map[point] == value + 1
+                    && !visited.contains(&point)
+                {
+                    trailheads.push_back(point);
+                    visited.insert(point);
+                }
+            }
+        }
+        ntrails
+    }
+
+    pub fn count_distinct_trails(&self, p: Point) -> usize {
+        let mut trailheads: VecDeque<Point> = VecDeque::new();
+        trailheads.push_back(p);
+        let trails: HashSet<Point> = self.trails();
+        let mut ntrails: usize = 0;
+        while let Some(p) = trailheads.pop_front() {
+            let value: u32 = self.trail_map[p];
+            if value == 9 {
+                ntrails += 1;
+            }
+            for point in self.trail_map.neighbors4(p) {
+                if trails.contains(&point) && self.trail_map[point] == value + 1 {
+                    trailheads.push_back(point);
+                }
+            }
+        }
+        ntrails
+    }
+}
+
+/// Answers questions about single points of the map in `aoc repl`.
+struct Survey<'a> {
+    map: &'a TopographicMap,
+}
+
+impl Explore for Survey<'_> {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("trailheads", "list the trailheads"),
+            ("score <row> <column>", "count the peaks reachable from a point"),
+            ("rating <row> <column>", "count the distinct trails from a point"),
+        ]
+    }
+
+    fn exec(&mut self, command: &str, args: &[&str]) -> Result<String, DynError> {
+        if command == "trailheads" {
+            let trailheads: Vec<String> =
+                self.map.find_trailheads().iter().map(|p| format!("{p:?}")).collect();
+            return Ok(trailheads.join(" "));
+        }
+        let p: Point = (arg(args, 0, "row")?, arg(args, 1, "column")?);
+        if !self.map.trail_map.contains(p) {
+            return Err(format!("{p:?} is off the map").into());
+        }
+        let count = match command {
+            "score" => self.map.count_trailheads(p),
+            _ => self.map.count_distinct_trails(p),
+        };
+        Ok(count.to_string())
+    }
+}
+
+const RADIX: u32 = 10u32;
+
+impl Solver for Day10 {
+    type Parsed = TopographicMap;
+
+    fn args(&self) -> &DayArgs {
+        &self.args
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, DynError> {
+        debug!("parse day 10");
+        let numbers = Grid::parse(input, |c| c.to_digit(RADIX))?;
+        Ok(TopographicMap::new(numbers))
+    }
+
+    fn part1(&self, trail_map: &Self::Parsed) -> Result<Solution, DynError> {
+        Ok(Solution::new().with_part1(trail_map.score_all_trailheads()))
+    }
+
+    fn part2(&self, trail_map: &Self::Parsed) -> Result<Solution, DynError> {
+        let n_trailheads: usize = trail_map.count_all_trailheads();
+        Ok(Solution::new().with_part2(n_trailheads))
+    }
+
+    fn explore<'a>(&self, map: &'a Self::Parsed) -> Option<Box<dyn Explore + 'a>> {
+        Some(Box::new(Survey { map }))
+    }
+
+    fn generate(&self, rng: &mut InputRng, size: Option<usize>) -> Option<String> {
+        let side = size.unwrap_or(50).max(1);
+        let mut heights: Grid<u32> = Grid::filled(side, side, 0);
+        for p in heights.positions().collect::<Vec<_>>() {
+            heights[p] = rng.gen_range(0..10);
+        }
+        // Random heights seldom climb evenly, so lay some trails from 0 up to 9.
+        for _ in 0..side * side / 20 {
+            let mut p = (rng.gen_range(0..side), rng.gen_range(0..side));
+            heights[p] = 0;
+            for height in 1..10 {
+                let next: Vec<(usize, usize)> = heights.neighbors4(p).collect();
+                p = next[rng.gen_range(0..next.len())];
+                heights[p] = height;
+            }
+        }
+        Some(format!("{heights}\n"))
+    }
+}
@@ -0,0 +1,134 @@
+use clap::Parser;
+use log::debug;
+
+use rand::Rng;
+use std::collections::HashMap;
+
+use crate::commands::{DayArgs, DynError, Parts, Puzzle, Registration, Solution, Solver};
+use crate::utils::random::InputRng;
+use crate::utils::AocError;
+
+#[derive(Parser, Debug, Default)]
+pub struct Day11 {
+    #[clap(flatten)]
+    args: DayArgs,
+}
+
+inventory::submit! {
+    Registration::new::<Day11>(Puzzle {
+        year: 2024,
+        day: 11,
+        title: "Plutonian Pebbles",
+        parts: Parts::Both,
+        input: "day11",
+    })
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Node {
+    id: u64,
+    height: u64,
+    coord: (usize, usize),
+}
+
+impl Node {
+    pub fn new(id: u64, height: u64, coord: (usize, usize)) -> Self {
+        Self { id, height, coord }
+    }
+
+    pub fn dist(&self, other: &Self) -> usize {
+        self.coord.0.abs_diff(other.coord.0) + self.coord.1.abs_diff(other.coord.1)
+    }
+
+    pub fn descendent(&self, other: &Self) -> Node {
+        Self { id: self.id, height: other.height, coord: other.coord }
+    }
+
+    pub fn connected(&self, other: &Self) -> Option<Node> {
+        if self.dist(other) == 1 {
+            Some(self.descendent(other))
+        } else {
+            None
+        }
+    }
+}
+
+// If the stone is engraved with the number 0, it is replaced by a stone engraved with the number 1.
+// engraved with an even number of digits, it is replaced by two stones. The left half of the digits are engraved on the new left stone, and the right half of the digits are engraved on the new right stone. (The new numbers don't keep extra leading zeroes: 1000 would become stones 10 and 0.)
+// If none of the other rules apply, the stone is replaced by a new stone; the old stone's number multiplied by 2024 is engraved on the new stone.
+/// Count the stones left after blinking `n` times, or `None` if a stone's number outgrows a `u64`.
+pub fn blink(stonevec: &[(u64, usize)], n: usize) -> Option<usize> {
+    let mut stone_map = stonevec.iter().fold(HashMap::new(), |mut acc, c| {
+        *acc.entry(c.0).or_insert(0) += c.1;
+        acc
+    });
+    debug!("stone_map: {:?}", stone_map);
+    for _ in 0..n {
+        let mut stonevec: Vec<(u64, usize)> = Vec::new();
+        for (x, i) in stone_map.iter() {
+            stonevec.extend(morph((*x, *i))?);
+        }
+        stone_map = stonevec.iter().fold(HashMap::new(), |mut acc, c| {
+            *acc.entry(c.0).or_insert(0) += c.1;
+            acc
+        });
+        //println!("stone_map({:?}): {:?}", i, stone_map);
+    }
+
+    Some(stone_map.values().sum())
+}
+
+fn overflowed() -> DynError {
+    AocError::NoSolution("a stone's number no longer fits in 64 bits".to_string()).into()
+}
+
+impl Solver for Day11 {
+    type Parsed = Vec<(u64, usize)>;
+
+    fn args(&self) -> &DayArgs {
+        &self.args
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, DynError> {
+        input
+            .split_whitespace()
+            .map(|x| match x.parse::<u64>() {
+                Ok(stone) => Ok((stone, 1)),
+                Err(_) => Err(AocError::at(input, x, "stone number").into()),
+            })
+            .collect()
+    }
+
+    fn part1(&self, stonevec: &Self::Parsed) -> Result<Solution, DynError> {
+        Ok(Solution::new().with_part1(blink(stonevec, 25).ok_or_else(overflowed)?))
+    }
+
+    fn part2(&self, stonevec: &Self::Parsed) -> Result<Solution, DynError> {
+        Ok(Solution::new().with_part2(blink(stonevec, 75).ok_or_else(overflowed)?))
+    }
+
+    fn generate(&self, rng: &mut InputRng, size: Option<usize>) -> Option<String> {
+        let stones: Vec<String> = (0..size.unwrap_or(8).max(1))
+            .map(|_| {
+                let ndigits = rng.gen_range(1..=7);
+                rng.gen_range(0..10_u64.pow(ndigits)).to_string()
+            })
+            .collect();
+        Some(stones.join(" ") + "\n")
+    }
+}
+
+/// The stones one stone turns into after a blink, or `None` if the new number outgrows a `u64`.
+pub fn morph(stone: (u64, usize)) -> Option<Vec<(u64, usize)>> {
+    let (number, count) = stone;
+    if number == 0 {
+        return Some(vec![(1, count)]);
+    }
+    let ndigits = number.ilog10() + 1;
+    if ndigits % 2 == 0 {
+        let half = 10_u64.pow(ndigits / 2);
+        Some(vec![(number / half, count), (number % half, count)])
+    } else {
+        Some(vec![(number.checked_mul(2024)?, count)])
+    }
+}
@@ -0,0 +1,138 @@
+use clap::Parser;
+
+use rand::Rng;
+use std::collections::VecDeque;
+
+use crate::commands::{DayArgs, DynError, Parts, Puzzle, Registration, Solution, Solver};
+use crate::utils::grid::{Grid, Position};
+use crate::utils::random::InputRng;
+
+#[derive(Parser, Debug, Default)]
+pub struct Day12 {
+    #[clap(flatten)]
+    args: DayArgs,
+}
+
+inventory::submit! {
+    Registration::new::<Day12>(Puzzle {
+        year: 2024,
+        day: 12,
+        title: "Garden Groups",
+        parts: Parts::Both,
+        input: "day12",
+    })
+}
+
+pub type GardenPlot = (usize, usize);
+
+/// Whether the plot `drow` rows and `dcol` columns from `plot` grows the same plant.
+fn same_plant(garden: &Grid<char>, plot: GardenPlot, drow: isize, dcol: isize) -> bool {
+    garden.offset(plot, drow, dcol).is_some_and(|n| garden[n] == garden[plot])
+}
+
+/// The corners of the region `plot` belongs to that touch `plot`, which equals the number of
+/// sides of that region once summed over all of its plots.
+pub fn count_plot_corners(garden: &Grid<char>, plot: GardenPlot) -> usize {
+    let diagonals = [(-1, -1), (-1, 1), (1, -1), (1, 1)];
+    diagonals
+        .iter()
+        .filter(|&&(drow, dcol)| {
+            let vertical = same_plant(garden, plot, drow, 0);
+            let horizontal = same_plant(garden, plot, 0, dcol);
+            let exterior = !vertical && !horizontal;
+            let interior = vertical && horizontal && !same_plant(garden, plot, drow, dcol);
+            exterior || interior
+        })
+        .count()
+}
+
+/// Split the garden into regions of adjacent plots growing the same plant.
+pub fn regions(garden: &Grid<char>) -> Vec<Vec<GardenPlot>> {
+    let mut evaluated: Grid<bool> = Grid::filled(garden.nrows(), garden.ncols(), false);
+    let mut regions: Vec<Vec<GardenPlot>> = Vec::new();
+    for start in garden.positions() {
+        if evaluated[start] {
+            continue;
+        }
+        evaluated[start] = true;
+        let mut region: Vec<GardenPlot> = Vec::new();
+        let mut queue: VecDeque<GardenPlot> = VecDeque::from([start]);
+        while let Some(parent) = queue.pop_front() {
+            region.push(parent);
+            for n in garden.neighbors4(parent) {
+                if garden[n] == garden[parent] && !evaluated[n] {
+                    evaluated[n] = true;
+                    queue.push_back(n);
+                }
+            }
+        }
+        regions.push(region);
+    }
+    regions
+}
+
+/// The area and number of sides of every region, as `(area, area * sides)`.
+pub fn count_corners(garden: &Grid<char>) -> Vec<(usize, usize)> {
+    regions(garden)
+        .iter()
+        .map(|region| {
+            let ncorners: usize = region.iter().map(|&p| count_plot_corners(garden, p)).sum();
+            (region.len(), region.len() * ncorners)
+        })
+        .collect()
+}
+
+/// The area and perimeter of every region.
+pub fn count_exposed_sides(garden: &Grid<char>) -> Vec<(usize, usize)> {
+    regions(garden)
+        .iter()
+        .map(|region| {
+            let nedges: usize = region
+                .iter()
+                .map(|&p| garden.neighbors4(p).filter(|&n| garden[n] == garden[p]).count())
+                .sum();
+            (region.len(), 4 * region.len() - nedges)
+        })
+        .collect()
+}
+
+impl Solver for Day12 {
+    type Parsed = Grid<char>;
+
+    fn args(&self) -> &DayArgs {
+        &self.args
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, DynError> {
+        Ok(Grid::parse(input, |c| c.is_ascii_uppercase().then_some(c))?)
+    }
+
+    fn part1(&self, plants: &Self::Parsed) -> Result<Solution, DynError> {
+        let answer: usize = count_exposed_sides(plants).iter().map(|(x, y)| x * y).sum();
+        Ok(Solution::new().with_part1(answer))
+    }
+
+    fn part2(&self, plants: &Self::Parsed) -> Result<Solution, DynError> {
+        let answer: usize = count_corners(plants).iter().map(|(_, y)| y).sum();
+        Ok(Solution::new().with_part2(answer))
+    }
+
+    fn generate(&self, rng: &mut InputRng, size: Option<usize>) -> Option<String> {
+        let side = size.unwrap_or(140).max(1);
+        // Each plot grows the plant of its nearest seed, which makes regions of all shapes.
+        let seeds: Vec<(Position, char)> = (0..(side * side / 40).max(1))
+            .map(|_| {
+                let seed = (rng.gen_range(0..side), rng.gen_range(0..side));
+                (seed, char::from(b'A' + rng.gen_range(0..26)))
+            })
+            .collect();
+        let mut plants: Grid<char> = Grid::filled(side, side, 'A');
+        for p in plants.positions().collect::<Vec<_>>() {
+            let nearest = seeds
+                .iter()
+                .min_by_key(|((row, column), _)| p.0.abs_diff(*row) + p.1.abs_diff(*column));
+            plants[p] = nearest.map_or('A', |&(_, plant)| plant);
+        }
+        Some(format!("{plants}\n"))
+    }
+}
@@ -1,28 +1,38 @@
-use std::ops;
-use std::path::PathBuf;
-
 use clap::Parser;
+use std::ops;
 
-use env_logger;
 use log::debug;
 use nom::bytes::complete::tag;
 use nom::character::complete::digit1;
 use nom::character::complete::line_ending;
 use nom::character::complete::one_of;
 use nom::character::complete::space1;
+use nom::combinator::map_res;
 use nom::multi::many1;
 use nom::multi::separated_list1;
 use nom::sequence::separated_pair;
 use nom::sequence::terminated;
 use nom::IResult;
-use std::fs;
+use rand::Rng;
 
-use super::{CommandImpl, DynError};
+use crate::commands::{DayArgs, DynError, Parts, Puzzle, Registration, Solution, Solver};
+use crate::utils::from_nom;
+use crate::utils::random::InputRng;
 
-#[derive(Parser, Debug)]
+#[derive(Parser, Debug, Default)]
 pub struct Day13 {
-    #[clap(long, short)]
-    input: PathBuf,
+    #[clap(flatten)]
+    args: DayArgs,
+}
+
+inventory::submit! {
+    Registration::new::<Day13>(Puzzle {
+        year: 2024,
+        day: 13,
+        title: "Claw Contraption",
+        parts: Parts::Both,
+        input: "day13",
+    })
 }
 
 #[derive(Debug, Copy, Clone, Hash, PartialEq)]
@@ -55,21 +65,6 @@ impl ops::Mul<usize> for Button {
     }
 }
 
-pub trait Indexable {
-    fn row(&self) -> usize;
-    fn column(&self) -> usize;
-}
-
-impl Indexable for Button {
-    fn row(&self) -> usize {
-        self.row
-    }
-
-    fn column(&self) -> usize {
-        self.column
-    }
-}
-
 #[derive(Debug, Copy, Clone, Hash, PartialEq)]
 pub struct MachineConfiguration {
     button_a: Button,
@@ -84,9 +79,7 @@ impl MachineConfiguration {
 }
 
 fn my_digit(input: &str) -> IResult<&str, usize> {
-    let (input, digits) = digit1(input)?;
-    let x: usize = digits.parse::<usize>().unwrap();
-    Ok((input, x))
+    map_res(digit1, str::parse)(input)
 }
 
 fn parse_x(input: &str) -> IResult<&str, usize> {
@@ -173,7 +166,7 @@ impl ops::Mul<usize> for Equation {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct EquationSolver {
     eqn1: Equation,
     eqn2: Equation,
@@ -215,14 +208,14 @@ impl EquationSolver {
         if (self.eqn1.eqn.0 * a + self.eqn1.eqn.1 * b == self.eqn1.eqn.2)
             && (self.eqn2.eqn.0 * a + self.eqn2.eqn.1 * b == self.eqn2.eqn.2)
         {
-            println!("a: {a}, b: {b}");
+            debug!("a: {a}, b: {b}");
             return Some((a, b));
         }
 
         None
     }
 
-    fn part2(&mut self) {
+    pub fn part2(&mut self) {
         self.eqn1 = self.eqn1.part2(10000000000000usize);
         self.eqn2 = self.eqn2.part2(10000000000000usize);
     }
@@ -259,31 +252,67 @@ fn parse_command(input: &str) -> IResult<&str, EquationSolver> {
     Ok((input, EquationSolver::new(eqn1, eqn2)))
 }
 
-fn parse_challenge(input: &str) -> IResult<&str, Vec<EquationSolver>> {
+pub fn parse_challenge(input: &str) -> IResult<&str, Vec<EquationSolver>> {
     let (input, commands) = separated_list1(many1(line_ending), parse_command)(input)?;
     Ok((input, commands))
 }
 
-impl CommandImpl for Day13 {
-    fn main(&self) -> Result<(), DynError> {
-        env_logger::init();
-        let blob_string = fs::read_to_string(&self.input)?;
+impl Solver for Day13 {
+    type Parsed = Vec<EquationSolver>;
+
+    fn args(&self) -> &DayArgs {
+        &self.args
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, DynError> {
+        Ok(from_nom(input, parse_challenge(input))?)
+    }
+
+    fn part1(&self, equations: &Self::Parsed) -> Result<Solution, DynError> {
         let mut tokens: usize = 0;
-        match parse_challenge(&blob_string) {
-            Ok((_, mut equations)) => {
-                for eqn in equations.iter_mut() {
-                    eqn.part2();
-                    if let Some(soln) = eqn.solve() {
-                        tokens += 3 * soln.0 + soln.1;
-                        debug!("solution for {:?} = {:?}", eqn, soln);
-                    }
-                }
+        for eqn in equations.clone().iter_mut() {
+            if let Some(soln) = eqn.solve() {
+                tokens += 3 * soln.0 + soln.1;
             }
-            Err(error) => panic!("Problem opening the file: {error:?}"),
-        };
-        println!("tokens: {tokens}");
+        }
 
-        Ok(())
+        Ok(Solution::new().with_part1(tokens))
+    }
+
+    fn part2(&self, equations: &Self::Parsed) -> Result<Solution, DynError> {
+        let mut tokens: usize = 0;
+        for eqn in equations.clone().iter_mut() {
+            eqn.part2();
+            if let Some(soln) = eqn.solve() {
+                tokens += 3 * soln.0 + soln.1;
+                debug!("solution for {:?} = {:?}", eqn, soln);
+            }
+        }
+
+        Ok(Solution::new().with_part2(tokens))
+    }
+
+    fn generate(&self, rng: &mut InputRng, size: Option<usize>) -> Option<String> {
+        let mut machines: Vec<String> = Vec::new();
+        for _ in 0..size.unwrap_or(320) {
+            let a = (rng.gen_range(10..100), rng.gen_range(10..100));
+            let mut b = (rng.gen_range(10..100), rng.gen_range(10..100));
+            // Puzzle inputs never have buttons moving the same way, which leave no unique answer.
+            while a.0 * b.1 == a.1 * b.0 {
+                b = (rng.gen_range(10..100), rng.gen_range(10..100));
+            }
+            let (na, nb): (usize, usize) = (rng.gen_range(0..=100), rng.gen_range(0..=100));
+            let mut prize = (na * a.0 + nb * b.0, na * a.1 + nb * b.1);
+            // Move some prizes off the lattice the buttons reach.
+            if rng.gen_bool(0.25) {
+                prize.0 += rng.gen_range(1..50);
+            }
+            machines.push(format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                a.0, a.1, b.0, b.1, prize.0, prize.1
+            ));
+        }
+        Some(machines.join("\n"))
     }
 }
 
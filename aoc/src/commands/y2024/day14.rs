@@ -1,7 +1,6 @@
-use std::collections::HashSet;
-use std::path::PathBuf;
-
 use clap::Parser;
+use log::debug;
+use std::collections::HashSet;
 
 use nom::bytes::complete::tag;
 use nom::character::complete::digit1;
@@ -14,20 +13,49 @@ use nom::{
     combinator::{map_res, opt, recognize},
     sequence::preceded,
 };
-use std::fs;
+use rand::Rng;
 
-use super::{CommandImpl, DynError};
+use crate::commands::{DayArgs, DynError, Parts, Puzzle, Registration, Solution, Solver};
+use crate::utils::random::InputRng;
+use crate::utils::{from_nom, line};
 
 #[derive(Parser, Debug)]
 pub struct Day14 {
-    #[clap(long, short)]
-    input: PathBuf,
+    #[clap(flatten)]
+    args: DayArgs,
+    /// Width of the space the robots move in, in tiles
+    #[clap(long, default_value_t = COLUMNS, value_parser = clap::value_parser!(i32).range(1..))]
+    width: i32,
+    /// Height of the space the robots move in, in tiles
+    #[clap(long, default_value_t = ROWS, value_parser = clap::value_parser!(i32).range(1..))]
+    height: i32,
+}
+
+impl Default for Day14 {
+    fn default() -> Self {
+        Self { args: DayArgs::default(), width: COLUMNS, height: ROWS }
+    }
 }
 
+inventory::submit! {
+    Registration::new::<Day14>(Puzzle {
+        year: 2024,
+        day: 14,
+        title: "Restroom Redoubt",
+        parts: Parts::Both,
+        input: "day14",
+    })
+}
+
+/// The size of the space in the puzzle input, which the examples shrink.
 const ROWS: i32 = 103;
-const MIDROW: i32 = 51;
 const COLUMNS: i32 = 101;
-const MIDCOLUMN: i32 = 50;
+
+/// How long part one watches the robots for, in seconds.
+const SECONDS: usize = 100;
+
+/// The number of rows and columns of the space the robots move in.
+pub type Space = (i32, i32);
 
 #[derive(Debug, Copy, Clone)]
 pub struct Robot {
@@ -58,9 +86,7 @@ impl Robot {
         self.colbar
     }
 
-    pub fn displace(&mut self) {
-        let nrows: i32 = ROWS;
-        let ncols: i32 = COLUMNS;
+    pub fn displace(&mut self, (nrows, ncols): Space) {
         self.row = (self.row + self.rowbar).rem_euclid(nrows);
         self.column = (self.column + self.colbar).rem_euclid(ncols);
     }
@@ -110,8 +136,8 @@ fn parse_robot(input: &str) -> IResult<&str, Robot> {
     Ok((input, r))
 }
 
-fn parse_robots(input: &str) -> IResult<&str, Vec<Robot>> {
-    let (input, robots) = separated_list1(line_ending, parse_robot)(input)?;
+pub fn parse_robots(input: &str) -> IResult<&str, Vec<Robot>> {
+    let (input, robots) = separated_list1(line_ending, line(parse_robot))(input)?;
     Ok((input, robots))
 }
 
@@ -123,16 +149,17 @@ fn parse_i32(input: &str) -> IResult<&str, i32> {
     Ok((i, number))
 }
 
-fn count_quadrant(q: usize, robots: &[Robot]) -> usize {
+fn count_quadrant(q: usize, robots: &[Robot], (nrows, ncols): Space) -> usize {
     let mut n: usize = 0;
+    let (midrow, midcolumn) = (nrows / 2, ncols / 2);
     let row_range = match q {
-        1 | 3 => 0i32..MIDROW,
-        2 | 4 => (MIDROW + 1)..ROWS,
+        1 | 3 => 0i32..midrow,
+        2 | 4 => (midrow + 1)..nrows,
         _ => panic!(),
     };
     let col_range = match q {
-        1 | 2 => 0i32..MIDCOLUMN,
-        3 | 4 => (MIDCOLUMN + 1)..COLUMNS,
+        1 | 2 => 0i32..midcolumn,
+        3 | 4 => (midcolumn + 1)..ncols,
         _ => panic!(),
     };
     for robot in robots.iter() {
@@ -143,9 +170,10 @@ fn count_quadrant(q: usize, robots: &[Robot]) -> usize {
     n
 }
 
-pub fn show(robots: &[Robot]) {
-    for row in 0..ROWS {
-        for col in 0..COLUMNS {
+pub fn show(robots: &[Robot], (nrows, ncols): Space) -> String {
+    let mut picture = String::new();
+    for row in 0..nrows {
+        for col in 0..ncols {
             let mut printed: bool = false;
             for robot in robots.iter() {
                 if row == robot.row() && col == robot.column() {
@@ -153,48 +181,85 @@ pub fn show(robots: &[Robot]) {
                 }
             }
             if !printed {
-                print!(" ");
+                picture.push(' ');
             } else {
-                print!("*");
+                picture.push('*');
             }
         }
-        println!();
+        picture.push('\n');
+    }
+    picture
+}
+
+impl Day14 {
+    fn space(&self) -> Space {
+        (self.height, self.width)
     }
 }
 
-impl CommandImpl for Day14 {
-    fn main(&self) -> Result<(), DynError> {
-        let blob_string = fs::read_to_string(&self.input)?;
-        let Ok((_, mut robots)) = parse_robots(&blob_string) else { todo!() };
-        for _i in 0..1000 {
+impl Solver for Day14 {
+    type Parsed = Vec<Robot>;
+
+    fn args(&self) -> &DayArgs {
+        &self.args
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, DynError> {
+        Ok(from_nom(input, parse_robots(input))?)
+    }
+
+    fn part1(&self, original: &Self::Parsed) -> Result<Solution, DynError> {
+        let mut robots = original.clone();
+        for _i in 0..SECONDS {
             for robot in robots.iter_mut() {
-                robot.displace();
+                robot.displace(self.space());
             }
         }
 
         let mut nquad: [usize; 4] = [0; 4];
         for (i, item) in nquad.iter_mut().enumerate() {
-            //nquad[i] = count_quadrant(i + 1, &robots);
-            *item = count_quadrant(i + 1, &robots);
+            *item = count_quadrant(i + 1, &robots, self.space());
         }
-        println!("nquad {:?}", nquad);
         let quadprod: usize = nquad.iter().product();
-        println!("product {:?}", quadprod);
+        Ok(Solution::new().with_part1(quadprod).with_diagnostic(format!("nquad {:?}", nquad)))
+    }
 
-        let Ok((_, mut robots)) = parse_robots(&blob_string) else { todo!() };
+    fn part2(&self, original: &Self::Parsed) -> Result<Solution, DynError> {
+        let mut solution = Solution::new();
+        let mut robots = original.clone();
         for i in 0..10000 {
             robots.sort_unstable_by_key(|r| (r.row, r.column));
             for robot in robots.iter_mut() {
-                robot.displace();
+                robot.displace(self.space());
             }
             let hashset: HashSet<(i32, i32)> =
                 robots.iter().map(|r| (r.row, r.column)).collect::<HashSet<_>>();
             if hashset.len() == robots.len() {
-                println!("iteration {i}");
-                show(&robots);
+                debug!("iteration {i}\n{}", show(&robots, self.space()));
+                if solution.part2.is_none() {
+                    solution = solution.with_part2(i + 1);
+                }
             }
         }
-        Ok(())
+        Ok(solution)
+    }
+
+    fn generate(&self, rng: &mut InputRng, size: Option<usize>) -> Option<String> {
+        let (rows, columns) = self.space();
+        let area = (rows * columns) as usize;
+        let nrobots = size.unwrap_or(500).clamp(1, area);
+        // Pick a moment when every robot stands on its own tile, and run them back from there.
+        let moment: i32 = rng.gen_range(1..10000);
+        let mut input = String::new();
+        for tile in rand::seq::index::sample(rng, area, nrobots) {
+            let (row, column) =
+                ((tile / columns as usize) as i32, (tile % columns as usize) as i32);
+            let (rowbar, colbar): (i32, i32) = (rng.gen_range(-99..100), rng.gen_range(-99..100));
+            let row = (row - rowbar * moment).rem_euclid(rows);
+            let column = (column - colbar * moment).rem_euclid(columns);
+            input.push_str(&format!("p={column},{row} v={colbar},{rowbar}\n"));
+        }
+        Some(input)
     }
 }
 
@@ -0,0 +1,430 @@
+use clap::Parser;
+use log::debug;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::fmt;
+
+use nom::character::complete::line_ending;
+use nom::multi::separated_list1;
+use nom::IResult;
+use nom::{
+    character::complete::one_of,
+    multi::{many0, many1},
+};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::commands::explore::{arg, Explore};
+use crate::commands::{DayArgs, DynError, Parts, Puzzle, Registration, Solution, Solver};
+use crate::utils::geometry::Direction;
+use crate::utils::grid::Grid;
+use crate::utils::random::InputRng;
+use crate::utils::{from_nom, AocError, ParseError};
+
+#[derive(Parser, Debug, Default)]
+pub struct Day15 {
+    #[clap(flatten)]
+    args: DayArgs,
+}
+
+inventory::submit! {
+    Registration::new::<Day15>(Puzzle {
+        year: 2024,
+        day: 15,
+        title: "Warehouse Woes",
+        parts: Parts::Both,
+        input: "day15",
+    })
+}
+
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq)]
+pub enum Object {
+    Robot,
+    BoxLeft,
+    BoxRight,
+    Box,
+    #[default]
+    Wall,
+    Space,
+}
+
+impl Object {
+    pub fn new(tick: char) -> Option<Self> {
+        match tick {
+            '.' => Some(Object::Space),
+            '[' => Some(Object::BoxLeft),
+            ']' => Some(Object::BoxRight),
+            'O' => Some(Object::Box),
+            '@' => Some(Object::Robot),
+            '#' => Some(Object::Wall),
+            _ => None,
+        }
+    }
+
+    pub fn to_char(&self) -> char {
+        match *self {
+            Object::Space => '.',
+            Object::BoxLeft => '[',
+            Object::BoxRight => ']',
+            Object::Box => 'O',
+            Object::Robot => '@',
+            Object::Wall => '#',
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Warehouse {
+    grid: Grid<Object>,
+    robot_position: (usize, usize),
+}
+
+pub type Point = (usize, usize);
+
+/// Where the one robot in `grid` stands.
+fn find_robot(grid: &Grid<Object>) -> Result<Point, AocError> {
+    let mut robots = grid.positions().filter(|&p| grid[p] == Object::Robot);
+    match (robots.next(), robots.next()) {
+        (Some(robot), None) => Ok(robot),
+        (None, _) => Err(ParseError::new("the warehouse has no robot".to_string()).into()),
+        (Some(_), Some(_)) => {
+            Err(ParseError::new("the warehouse has more than one robot".to_string()).into())
+        }
+    }
+}
+
+impl Warehouse {
+    pub fn new(grid: Grid<Object>) -> Result<Self, AocError> {
+        let robot_position = find_robot(&grid)?;
+        Ok(Warehouse { grid, robot_position })
+    }
+}
+
+impl Warehouse {
+    /// The square one step from `p`, or `None` off the edge of a warehouse that is not walled
+    /// in, which blocks the move like a wall.
+    fn next_position(&self, d: Direction, p: Point) -> Option<Point> {
+        d.step(p, self.grid.dimensions())
+    }
+
+    pub fn show_robot_position(&self) {
+        debug!("robot position: {:?}", self.robot_position);
+    }
+
+    pub fn show_rows(&self) {
+        debug!("\n{}", self.grid);
+    }
+
+    fn vertical_move(&mut self, d: Direction) {
+        let mut curr_p: VecDeque<(usize, usize)> = VecDeque::new();
+        let mut visited: HashSet<(usize, usize)> = HashSet::new();
+        let mut steps: Vec<(Point, Point)> = Vec::new();
+        curr_p.push_back(self.robot_position);
+        while let Some((row, column)) = curr_p.pop_front() {
+            let Some((next_row, next_column)) = self.next_position(d, (row, column)) else {
+                return;
+            };
+            if !visited.contains(&(row, column)) {
+                steps.push(((row, column), (next_row, next_column)));
+                visited.insert((row, column));
+            }
+            match self.grid[(next_row, next_column)] {
+                Object::Wall => {
+                    return;
+                }
+                Object::Box => {
+                    curr_p.push_back((next_row, next_column));
+                }
+                Object::BoxLeft => {
+                    curr_p.push_back((next_row, next_column));
+                    if self.grid[(row, column)] != self.grid[(next_row, next_column)] {
+                        curr_p.push_back((next_row, next_column + 1));
+                    }
+                }
+                Object::BoxRight => {
+                    curr_p.push_back((next_row, next_column));
+                    if self.grid[(row, column)] != self.grid[(next_row, next_column)] {
+                        curr_p.push_back((next_row, next_column - 1));
+                    }
+                }
+                Object::Space => {}
+                _ => (),
+            }
+        }
+        self.push(steps);
+    }
+
+    fn horizontal_move(&mut self, d: Direction) {
+        let mut curr_p: VecDeque<(usize, usize)> = VecDeque::new();
+        let mut steps: Vec<(Point, Point)> = Vec::new();
+        curr_p.push_back(self.robot_position);
+        while let Some((row, column)) = curr_p.pop_front() {
+            let Some((next_row, next_column)) = self.next_position(d, (row, column)) else {
+                return;
+            };
+            steps.push(((row, column), (next_row, next_column)));
+            match self.grid[(next_row, next_column)] {
+                Object::Wall | Object::Robot => {
+                    return;
+                }
+                Object::BoxLeft | Object::BoxRight | Object::Box => {
+                    curr_p.push_back((next_row, next_column));
+                }
+                Object::Space => {
+                    break;
+                }
+            }
+        }
+        self.push(steps);
+    }
+
+    /// Move everything in `steps`, pairs of where it is and where it goes, the first of them
+    /// being the robot. The last to be found moves first, so that nothing is overwritten.
+    fn push(&mut self, mut steps: Vec<(Point, Point)>) {
+        if let Some(&(_, robot)) = steps.first() {
+            self.robot_position = robot;
+        }
+        while let Some((curr, next)) = steps.pop() {
+            self.grid[next] = self.grid[curr];
+            self.grid[curr] = Object::Space;
+        }
+    }
+
+    pub fn move_robot(&mut self, d: Direction) {
+        let (robot_row, robot_column) = self.robot_position;
+        let Some((next_row, next_column)) = self.next_position(d, self.robot_position) else {
+            return;
+        };
+        if self.grid[(next_row, next_column)] == Object::Wall {
+            return;
+        }
+        if self.grid[(next_row, next_column)] == Object::Space {
+            self.grid[(robot_row, robot_column)] = Object::Space;
+            self.grid[(next_row, next_column)] = Object::Robot;
+            self.robot_position = (next_row, next_column);
+            return;
+        }
+        let (mut last_next_row, mut last_next_column) = (next_row, next_column);
+        loop {
+            let Some((next_next_row, next_next_column)) =
+                self.next_position(d, (last_next_row, last_next_column))
+            else {
+                return;
+            };
+            if self.grid[(next_next_row, next_next_column)] == Object::Wall {
+                return;
+            }
+            if self.grid[(next_next_row, next_next_column)] == Object::Space {
+                self.grid[(robot_row, robot_column)] = Object::Space;
+                self.grid[(next_row, next_column)] = Object::Robot;
+                self.robot_position = (next_row, next_column);
+                self.grid[(next_next_row, next_next_column)] = Object::Box;
+                return;
+            }
+            last_next_row = next_next_row;
+            last_next_column = next_next_column;
+        }
+    }
+
+    /// Move the robot of the wide warehouse of part 2, where boxes take up two squares.
+    pub fn move_wide_robot(&mut self, d: Direction) {
+        if d.is_vertical() {
+            self.vertical_move(d);
+        } else {
+            self.horizontal_move(d);
+        }
+    }
+
+    pub fn sum_boxes(&self) -> usize {
+        let mut sumboxes: usize = 0;
+        for row in 0..self.grid.nrows() {
+            for column in 0..self.grid.ncols() {
+                if self.grid[(row, column)] == Object::Box
+                    || self.grid[(row, column)] == Object::BoxLeft
+                {
+                    let curr = row * 100 + column;
+                    sumboxes += curr;
+                }
+            }
+        }
+        sumboxes
+    }
+}
+
+fn parse_move_line(input: &str) -> IResult<&str, Vec<char>> {
+    let (input, row) = many1(one_of("<>^v"))(input)?;
+    Ok((input, row))
+}
+
+fn parse_moves(input: &str) -> IResult<&str, Vec<Direction>> {
+    let (input, _) = many0(line_ending)(input)?;
+    let (input, commands) = separated_list1(line_ending, parse_move_line)(input)?;
+    let line = commands
+        .into_iter()
+        .flatten()
+        .filter_map(Direction::from_arrow)
+        .collect::<Vec<Direction>>();
+    Ok((input, line))
+}
+
+pub fn show(warehouse: &Warehouse) {
+    debug!("robot: {:?}, ", warehouse.robot_position);
+    for row in warehouse.grid.rows() {
+        debug!("{}", row.iter().map(|x| format!("{:?}, ", x)).collect::<String>());
+    }
+}
+
+fn double_up(objects: &[Object]) -> Vec<Object> {
+    objects.iter().flat_map(|v| vec![*v, *v]).collect()
+}
+
+/// The warehouse of part 2, with everything but the robot twice as wide.
+fn widen(rows: &Grid<Object>) -> Result<Grid<Object>, AocError> {
+    let mut rows: Vec<Vec<Object>> = rows.rows().map(double_up).collect();
+    for row in rows.iter_mut() {
+        let mut box_conversion: bool = false;
+        let mut second_robot: bool = false;
+        for v in row.iter_mut() {
+            if *v == Object::Robot {
+                if second_robot {
+                    *v = Object::Space;
+                    second_robot = false;
+                } else {
+                    second_robot = true;
+                }
+            }
+            if *v == Object::Box {
+                if box_conversion {
+                    *v = Object::BoxRight;
+                    box_conversion = false;
+                } else {
+                    *v = Object::BoxLeft;
+                    box_conversion = true;
+                }
+            }
+        }
+    }
+    Grid::new(rows)
+}
+
+impl fmt::Display for Object {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+/// Replays the robot's moves in `aoc repl`.
+struct Replay<'a> {
+    rows: &'a Grid<Object>,
+    moves: &'a [Direction],
+}
+
+impl Explore for Replay<'_> {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("moves <k>", "show the warehouse after the robot's first k moves"),
+            ("wide <k>", "show the wide warehouse of part 2 after the robot's first k moves"),
+        ]
+    }
+
+    fn exec(&mut self, command: &str, args: &[&str]) -> Result<String, DynError> {
+        let k: usize = arg(args, 0, "number of moves")?;
+        let moves = &self.moves[..k.min(self.moves.len())];
+        let mut warehouse;
+        if command == "wide" {
+            warehouse = Warehouse::new(widen(self.rows)?)?;
+            moves.iter().for_each(|&m| warehouse.move_wide_robot(m));
+        } else {
+            warehouse = Warehouse::new(self.rows.clone())?;
+            moves.iter().for_each(|&m| warehouse.move_robot(m));
+        }
+        Ok(format!(
+            "{}\nafter {} of {} moves the boxes add up to {}",
+            warehouse.grid,
+            moves.len(),
+            self.moves.len(),
+            warehouse.sum_boxes()
+        ))
+    }
+}
+
+impl Solver for Day15 {
+    type Parsed = (Grid<Object>, Vec<Direction>);
+
+    fn args(&self) -> &DayArgs {
+        &self.args
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, DynError> {
+        let (rows, rest) = Grid::parse_prefix(input, Object::new)?;
+        find_robot(&rows)?;
+        // `rest` is the tail of `input`, so errors in the moves are placed in the whole input.
+        let moves = from_nom(input, parse_moves(rest))?;
+        Ok((rows, moves))
+    }
+
+    fn part1(&self, (rows, moves): &Self::Parsed) -> Result<Solution, DynError> {
+        let mut warehouse = Warehouse::new(rows.clone())?;
+        for m in moves.iter() {
+            warehouse.move_robot(*m);
+        }
+        Ok(Solution::new().with_part1(warehouse.sum_boxes()))
+    }
+
+    fn part2(&self, (rows, moves): &Self::Parsed) -> Result<Solution, DynError> {
+        let mut warehouse = Warehouse::new(widen(rows)?)?;
+        for m in moves.iter() {
+            warehouse.move_wide_robot(*m);
+        }
+
+        Ok(Solution::new().with_part2(warehouse.sum_boxes()))
+    }
+
+    fn explore<'a>(&self, (rows, moves): &'a Self::Parsed) -> Option<Box<dyn Explore + 'a>> {
+        Some(Box::new(Replay { rows, moves }))
+    }
+
+    fn generate(&self, rng: &mut InputRng, size: Option<usize>) -> Option<String> {
+        let side = size.unwrap_or(50).max(3);
+        let mut warehouse: Grid<char> = Grid::filled(side, side, '#');
+        for row in 1..side - 1 {
+            for column in 1..side - 1 {
+                warehouse[(row, column)] = match rng.gen_range(0..100) {
+                    0..8 => '#',
+                    8..33 => 'O',
+                    _ => '.',
+                };
+            }
+        }
+        warehouse[(rng.gen_range(1..side - 1), rng.gen_range(1..side - 1))] = '@';
+        let moves: Vec<u8> =
+            (0..8 * side * side).map(|_| *b"<>^v".choose(rng).unwrap_or(&b'<')).collect();
+        let moves: Vec<String> =
+            moves.chunks(1000).map(|line| String::from_utf8_lossy(line).into()).collect();
+        Some(format!("{warehouse}\n\n{}\n", moves.join("\n")))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_needs_one_robot() {
+        let day = Day15::default();
+        let error = day.parse("#####\n#.O.#\n#####\n\n<>\n").unwrap_err();
+        assert!(error.to_string().contains("no robot"), "{error}");
+        let error = day.parse("#####\n#@O@#\n#####\n\n<>\n").unwrap_err();
+        assert!(error.to_string().contains("more than one robot"), "{error}");
+        assert!(day.parse("#####\n#@X.#\n#####\n\n<>\n").is_err());
+    }
+
+    #[test]
+    fn test_edge_blocks_like_a_wall() -> Result<(), Box<dyn std::error::Error>> {
+        let day = Day15::default();
+        let parsed = day.parse("@.O\n.O.\n\n^<v>>>vv\n")?;
+        assert_eq!(day.part1(&parsed)?.part1, Some(104.into()));
+        assert!(day.part2(&parsed)?.part2.is_some());
+        Ok(())
+    }
+}
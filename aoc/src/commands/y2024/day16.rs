@@ -0,0 +1,221 @@
+use clap::Parser;
+use log::debug;
+
+use rand::Rng;
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::commands::{DayArgs, DynError, Parts, Puzzle, Registration, Solution, Solver};
+use crate::utils::geometry::{Direction, Heading};
+use crate::utils::grid::Grid;
+use crate::utils::random::{self, InputRng};
+use crate::utils::search::{dijkstra, Search, WeightedNeighbors};
+use crate::utils::{AocError, ParseError};
+
+#[derive(Parser, Debug, Default)]
+pub struct Day16 {
+    #[clap(flatten)]
+    args: DayArgs,
+}
+
+inventory::submit! {
+    Registration::new::<Day16>(Puzzle {
+        year: 2024,
+        day: 16,
+        title: "Reindeer Maze",
+        parts: Parts::Both,
+        input: "day16",
+    })
+}
+
+pub type Point = (usize, usize);
+
+#[derive(Default, Copy, Clone, Hash, PartialEq)]
+pub enum Object {
+    #[default]
+    Space,
+    Start,
+    End,
+    Wall,
+}
+
+impl Object {
+    pub fn new(tick: char) -> Option<Self> {
+        match tick {
+            '.' => Some(Object::Space),
+            'S' => Some(Object::Start),
+            'E' => Some(Object::End),
+            '#' => Some(Object::Wall),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Debug for Object {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Object::Wall => write!(f, "Wall "),
+            Object::End => write!(f, "End  "),
+            Object::Start => write!(f, "Start"),
+            Object::Space => write!(f, "Space"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Maze {
+    blueprint: Grid<Object>,
+    start: Heading,
+    end: Point,
+}
+
+impl Maze {
+    pub fn new(blueprint: Grid<Object>, start: Heading, end: Point) -> Self {
+        Maze { blueprint, start, end }
+    }
+
+    pub fn reachable(&self, p: Point) -> bool {
+        self.blueprint[p] != Object::Wall
+    }
+
+    pub fn nrows(&self) -> usize {
+        self.blueprint.nrows()
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.blueprint.ncols()
+    }
+}
+
+/// A reindeer can step forward, or turn and then step, paying for every quarter turn.
+impl WeightedNeighbors for Maze {
+    type Node = Heading;
+
+    fn weighted_neighbors(&self, h: &Heading) -> impl IntoIterator<Item = (Heading, usize)> {
+        let d = h.direction;
+        let turns = [(d, 0), (d.turn_right(), 1), (d.reverse(), 2), (d.turn_left(), 1)];
+        let position = h.position;
+        turns.into_iter().filter_map(move |(direction, quarter_turns)| {
+            let next = Heading::new(position, direction).step(self.blueprint.dimensions())?;
+            let score = quarter_turns * ROTATION_PENALTY + 1;
+            self.reachable(next.position).then_some((next, score))
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct ShortestPath {
+    maze: Maze,
+    search: Search<Heading>,
+}
+
+impl ShortestPath {
+    pub fn new(maze: Maze) -> Self {
+        let search = dijkstra(&maze, [maze.start]);
+        Self { maze, search }
+    }
+
+    /// The ways of facing on the end square that it is reached in at the lowest score.
+    fn best_ends(&self) -> Vec<Heading> {
+        let ends = Direction::ALL.map(|direction| Heading::new(self.maze.end, direction));
+        let best = self.best_score();
+        ends.into_iter().filter(|end| self.search.distance(end) == best).collect()
+    }
+
+    pub fn best_score(&self) -> Option<usize> {
+        Direction::ALL
+            .iter()
+            .filter_map(|&direction| self.search.distance(&Heading::new(self.maze.end, direction)))
+            .min()
+    }
+
+    /// The squares along any of the best paths.
+    pub fn nsquares_on_best_paths(&self) -> usize {
+        let headings = self.search.on_shortest_paths(self.best_ends());
+        headings.into_iter().map(|heading| heading.position).collect::<HashSet<Point>>().len()
+    }
+}
+
+pub fn find_start(maze: &Grid<Object>) -> Option<Heading> {
+    maze.find(&Object::Start).map(|p| Heading::new(p, Direction::East))
+}
+
+pub fn find_end(maze: &Grid<Object>) -> Option<Point> {
+    maze.find(&Object::End)
+}
+
+fn no_path() -> AocError {
+    AocError::NoSolution("no path from the start to the end".to_string())
+}
+
+const ROTATION_PENALTY: usize = 1000;
+
+impl Solver for Day16 {
+    /// Both parts read the same search, so it is run once while parsing.
+    type Parsed = ShortestPath;
+
+    fn args(&self) -> &DayArgs {
+        &self.args
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, DynError> {
+        let rows = Grid::parse(input, Object::new)?;
+        let start = find_start(&rows).ok_or(ParseError::new("the maze has no start".into()))?;
+        let end = find_end(&rows).ok_or(ParseError::new("the maze has no end".into()))?;
+        debug!("start: {start:?}");
+        debug!("end: {end:?}");
+        Ok(ShortestPath::new(Maze::new(rows, start, end)))
+    }
+
+    fn part1(&self, shortest_path: &Self::Parsed) -> Result<Solution, DynError> {
+        let best_score = shortest_path.best_score().ok_or_else(no_path)?;
+        Ok(Solution::new().with_part1(best_score))
+    }
+
+    fn part2(&self, shortest_path: &Self::Parsed) -> Result<Solution, DynError> {
+        shortest_path.best_score().ok_or_else(no_path)?;
+        Ok(Solution::new().with_part2(shortest_path.nsquares_on_best_paths()))
+    }
+
+    fn generate(&self, rng: &mut InputRng, size: Option<usize>) -> Option<String> {
+        let side = size.unwrap_or(141).max(5) | 1;
+        let mut open = random::perfect_maze(rng, side, side);
+        // Knock through some walls between corridors, so that there is more than one way round.
+        for row in 1..side - 1 {
+            for column in 1..side - 1 {
+                if (row + column) % 2 == 1 && rng.gen_bool(0.1) {
+                    open[(row, column)] = true;
+                }
+            }
+        }
+        let mut maze = open.map(|&open| if open { '.' } else { '#' });
+        maze[(side - 2, 1)] = 'S';
+        maze[(1, side - 2)] = 'E';
+        Some(format!("{maze}\n"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_needs_start_and_end() {
+        let day = Day16::default();
+        let error = day.parse("#####\n#..E#\n#####\n").unwrap_err();
+        assert!(error.to_string().contains("no start"), "{error}");
+        let error = day.parse("#####\n#S..#\n#####\n").unwrap_err();
+        assert!(error.to_string().contains("no end"), "{error}");
+    }
+
+    #[test]
+    fn test_no_path_is_an_error() -> Result<(), Box<dyn std::error::Error>> {
+        let day = Day16::default();
+        let maze = day.parse("#####\n#S#E#\n#####\n")?;
+        for error in [day.part1(&maze).unwrap_err(), day.part2(&maze).unwrap_err()] {
+            let error = error.downcast::<AocError>()?;
+            assert!(matches!(*error, AocError::NoSolution(_)), "{error}");
+        }
+        Ok(())
+    }
+}
@@ -1,33 +1,42 @@
-use std::path::PathBuf;
-
 use clap::Parser;
 
-use env_logger;
-use log::{debug, info};
+use log::{debug, trace};
 use nom::bytes::complete::tag;
 use nom::character::complete::digit1;
 use nom::character::complete::line_ending;
 use nom::character::complete::one_of;
 use nom::character::complete::space1;
+use nom::combinator::{map_res, verify};
 use nom::multi::many1;
 use nom::multi::separated_list1;
 use nom::sequence::separated_pair;
 use nom::sequence::terminated;
 use nom::IResult;
-use std::fs;
+use rand::Rng;
 
-use super::{CommandImpl, DynError};
+use crate::commands::explore::{arg_or, Explore};
+use crate::commands::{DayArgs, DynError, Parts, Puzzle, Registration, Solution, Solver};
+use crate::utils::from_nom;
+use crate::utils::random::InputRng;
 
-#[derive(Parser, Debug)]
+#[derive(Parser, Debug, Default)]
 pub struct Day17 {
-    #[clap(long, short)]
-    input: PathBuf,
+    #[clap(flatten)]
+    args: DayArgs,
+}
+
+inventory::submit! {
+    Registration::new::<Day17>(Puzzle {
+        year: 2024,
+        day: 17,
+        title: "Chronospatial Computer",
+        parts: Parts::One,
+        input: "day17",
+    })
 }
 
 fn my_digit(input: &str) -> IResult<&str, i32> {
-    let (input, digits) = digit1(input)?;
-    let x: i32 = digits.parse::<i32>().unwrap();
-    Ok((input, x))
+    map_res(digit1, str::parse)(input)
 }
 
 #[derive(Debug, Copy, Clone, Hash, PartialEq)]
@@ -150,33 +159,33 @@ impl Computer {
         let denominator: f32 = 2.0_f32.powi(combo);
         let tmp: f32 = numerator / denominator;
         let result: i32 = tmp.floor() as i32;
-        info!("adv({operand}) {:?} = {result}", self.register_a);
+        trace!("adv({operand}) {:?} = {result}", self.register_a);
         self.register_a = Register::A(result);
     }
 
     pub fn bxl(&mut self, operand: i32) {
         let lhs: i32 = self.register_b.value().unwrap();
         let result: i32 = lhs ^ operand;
-        info!("bxl {operand}: register {:?} = {lhs} xor {operand} = {result}", self.register_b);
+        trace!("bxl {operand}: register {:?} = {lhs} xor {operand} = {result}", self.register_b);
         self.register_b = Register::B(result);
     }
 
     pub fn bst(&mut self, operand: i32) {
         let combo: i32 = self.combo_operand(operand);
         let result: i32 = combo % 8i32;
-        info!("bst: set {:?} to combo({operand}):{combo} mod 8 = {result}", self.register_b);
+        trace!("bst: set {:?} to combo({operand}):{combo} mod 8 = {result}", self.register_b);
         self.register_b = Register::B(result);
     }
 
     pub fn jnz(&mut self, operand: i32) {
         let rega_value: i32 = self.register_a.value().unwrap();
         if rega_value == 0 {
-            info!("jnz op: {operand} - no jump");
+            trace!("jnz op: {operand} - no jump");
             return;
         }
         let combo: i32 = operand;
         self.jumped = true;
-        info!("jnz op: {operand}, reg: {rega_value}, instruction pointer jumped to {combo}");
+        trace!("jnz op: {operand}, reg: {rega_value}, instruction pointer jumped to {combo}");
         self.instruction_pointer = combo as usize;
     }
 
@@ -184,14 +193,14 @@ impl Computer {
         let regb_value: i32 = self.register_b.value().unwrap();
         let regc_value: i32 = self.register_c.value().unwrap();
         let result: i32 = regb_value ^ regc_value;
-        info!("bxc {_operand} - regb:{regb_value} xor regc:{regc_value} = {result}");
+        trace!("bxc {_operand} - regb:{regb_value} xor regc:{regc_value} = {result}");
         self.register_b = Register::B(result);
     }
 
     pub fn out(&mut self, operand: i32) {
         let combo: i32 = self.combo_operand(operand);
         let result: i32 = combo % 8i32;
-        info!("out {operand} - combo {combo} - output {result}");
+        trace!("out {operand} - combo {combo} - output {result}");
         self.printed_list.push(result);
     }
 
@@ -201,7 +210,7 @@ impl Computer {
         let denominator: f32 = 2.0_f32.powi(combo);
         let tmp: f32 = numerator / denominator;
         let result: i32 = tmp.floor() as i32;
-        info!("bdv({operand}) {:?} = {result}", self.register_b);
+        trace!("bdv({operand}) {:?} = {result}", self.register_b);
         self.register_b = Register::B(result);
     }
 
@@ -211,24 +220,20 @@ impl Computer {
         let denominator: f32 = 2.0_f32.powi(combo);
         let tmp: f32 = numerator / denominator;
         let result: i32 = tmp.floor() as i32;
-        info!("cdv({operand}) {:?} = {result}", self.register_c);
+        trace!("cdv({operand}) {:?} = {result}", self.register_c);
         self.register_c = Register::C(result);
     }
 
-    pub fn print_output(&self) {
-        for i in self.printed_list.iter() {
-            print!("{i},");
-        }
+    pub fn output(&self) -> String {
+        self.printed_list.iter().map(|i| i.to_string()).collect::<Vec<String>>().join(",")
     }
 
-    pub fn print_reverse(&self) {
-        for i in self.reverse_list.iter() {
-            print!("{:?},", i);
-        }
+    pub fn reverse_output(&self) -> String {
+        self.reverse_list.iter().map(|i| format!("{:?}", i)).collect::<Vec<String>>().join(",")
     }
 
     pub fn run_command(&mut self, command: Command) {
-        //info!(
+        //trace!(
         //    "run {:?} - registers {:?}, {:?}, {:?}",
         //    command, self.register_a, self.register_b, self.register_c
         //);
@@ -254,7 +259,7 @@ impl Computer {
             let command: Command = commands[self.instruction_pointer];
             self.run_command(command);
         }
-        info!("registers {:?}, {:?}, {:?}", self.register_a, self.register_b, self.register_c);
+        debug!("registers {:?}, {:?}, {:?}", self.register_a, self.register_b, self.register_c);
     }
 }
 
@@ -276,7 +281,8 @@ fn parse_registers(input: &str) -> IResult<&str, Vec<Register>> {
 
 fn parse_command(input: &str) -> IResult<&str, Command> {
     debug!("parse_command: {input}");
-    let (input, command) = separated_pair(my_digit, tag(","), my_digit)(input)?;
+    let opcode = verify(my_digit, |opcode| (0..8).contains(opcode));
+    let (input, command) = separated_pair(opcode, tag(","), my_digit)(input)?;
     Ok((input, Command::new(command.0, command.1)))
 }
 
@@ -288,7 +294,7 @@ fn parse_commands(input: &str) -> IResult<&str, Vec<Command>> {
     Ok((input, commands))
 }
 
-fn parse_challenge(input: &str) -> IResult<&str, (Vec<Register>, Vec<Command>)> {
+pub fn parse_challenge(input: &str) -> IResult<&str, (Vec<Register>, Vec<Command>)> {
     debug!("parse_challenge: {input}");
     let (input, registers) = parse_registers(input)?;
     let (input, _) = many1(line_ending)(input)?;
@@ -296,25 +302,107 @@ fn parse_challenge(input: &str) -> IResult<&str, (Vec<Register>, Vec<Command>)>
     Ok((input, (registers, commands)))
 }
 
-impl CommandImpl for Day17 {
-    fn main(&self) -> Result<(), DynError> {
-        env_logger::init();
-        debug!("main");
-        let blob_string = fs::read_to_string(&self.input)?;
-        match parse_challenge(&blob_string) {
-            Ok((_, (registers, commands))) => {
-                info!("commands: {:?}", commands);
-                let mut computer: Computer =
-                    Computer::new(registers[0], registers[1], registers[2]);
-                computer.run_commands(commands);
-                computer.print_output();
-                println!();
-                computer.print_reverse();
-            }
-            Err(error) => panic!("Problem opening the file: {error:?}"),
+/// Runs the program an instruction at a time in `aoc repl`.
+struct Debugger<'a> {
+    registers: &'a [Register],
+    commands: &'a [Command],
+    computer: Computer,
+}
+
+impl<'a> Debugger<'a> {
+    fn new(registers: &'a [Register], commands: &'a [Command]) -> Self {
+        let computer = Computer::new(registers[0], registers[1], registers[2]);
+        Self { registers, commands, computer }
+    }
+
+    fn state(&self) -> String {
+        let computer = &self.computer;
+        let next = match self.commands.get(computer.instruction_pointer) {
+            Some(command) => format!("{command:?}"),
+            None => "halted".to_string(),
         };
+        format!(
+            "ip {}: {next}\nregisters {:?}, {:?}, {:?}\noutput {}",
+            computer.instruction_pointer,
+            computer.register_a,
+            computer.register_b,
+            computer.register_c,
+            computer.output()
+        )
+    }
+}
 
-        Ok(())
+impl Explore for Debugger<'_> {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("step [n]", "run the next n instructions, 1 by default"),
+            ("run", "run until the program halts"),
+            ("state", "show the registers, the next instruction and the output so far"),
+            ("reset", "start the program again from the parsed registers"),
+        ]
+    }
+
+    fn exec(&mut self, command: &str, args: &[&str]) -> Result<String, DynError> {
+        match command {
+            "step" => {
+                for _ in 0..arg_or(args, 0, "count", 1_usize)? {
+                    let Some(&next) = self.commands.get(self.computer.instruction_pointer) else {
+                        break;
+                    };
+                    self.computer.run_command(next);
+                }
+            }
+            "run" => self.computer.run_commands(self.commands.to_vec()),
+            "reset" => *self = Self::new(self.registers, self.commands),
+            _ => {}
+        }
+        Ok(self.state())
+    }
+}
+
+impl Solver for Day17 {
+    type Parsed = (Vec<Register>, Vec<Command>);
+
+    fn args(&self) -> &DayArgs {
+        &self.args
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, DynError> {
+        debug!("main");
+        Ok(from_nom(input, parse_challenge(input))?)
+    }
+
+    fn part1(&self, (registers, commands): &Self::Parsed) -> Result<Solution, DynError> {
+        debug!("commands: {:?}", commands);
+        let mut computer: Computer = Computer::new(registers[0], registers[1], registers[2]);
+        computer.run_commands(commands.clone());
+        debug!("reverse: {}", computer.reverse_output());
+
+        Ok(Solution::new().with_part1(computer.output()))
+    }
+
+    fn explore<'a>(
+        &self,
+        (registers, commands): &'a Self::Parsed,
+    ) -> Option<Box<dyn Explore + 'a>> {
+        Some(Box::new(Debugger::new(registers, commands)))
+    }
+
+    fn generate(&self, rng: &mut InputRng, size: Option<usize>) -> Option<String> {
+        // Registers are `i32`s, so register A holds at most ten octal digits.
+        let ndigits = size.unwrap_or(10).clamp(1, 10) as u32;
+        let register_a: i32 = rng.gen_range(8_i32.pow(ndigits - 1)..8_i32.pow(ndigits));
+        let (k1, k2, k3) = (rng.gen_range(0..8), rng.gen_range(0..8), rng.gen_range(0..8));
+        // The shape of every real program: mix the low bits of A into B, print a digit of B, and
+        // shift A until it runs out, which is what makes the program halt.
+        let program = if rng.gen_bool(0.5) {
+            format!("2,4,1,{k1},7,5,1,{k2},4,{k3},5,5,0,3,3,0")
+        } else {
+            format!("2,4,1,{k1},7,5,0,3,4,{k3},1,{k2},5,5,3,0")
+        };
+        Some(format!(
+            "Register A: {register_a}\nRegister B: 0\nRegister C: 0\n\nProgram: {program}\n"
+        ))
     }
 }
 
@@ -336,4 +424,23 @@ mod test {
         assert_eq!(register, Register::A(30));
         Ok(())
     }
+
+    #[test]
+    fn test_debugger() -> Result<(), Box<dyn std::error::Error>> {
+        let input = "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,4,3,0\n";
+        let parsed = Day17::default().parse(input)?;
+        let mut debugger = Day17::default().explore(&parsed).unwrap();
+        let state = debugger.exec("step", &[])?;
+        assert_eq!(state, "ip 1: OUT(4)\nregisters A(364), B(0), C(0)\noutput ");
+        let state = debugger.exec("step", &["2"])?;
+        assert!(state.starts_with("ip 0: ADV(1)\nregisters A(364),"), "{state}");
+        assert!(state.ends_with("output 4"), "{state}");
+        let state = debugger.exec("run", &[])?;
+        assert!(state.starts_with("ip 3: halted"), "{state}");
+        assert!(state.ends_with("output 4,6,3,5,6,3,5,2,1,0"), "{state}");
+        let state = debugger.exec("reset", &[])?;
+        assert!(state.starts_with("ip 0: ADV(1)\nregisters A(729),"), "{state}");
+        assert!(debugger.exec("step", &["x"]).is_err());
+        Ok(())
+    }
 }
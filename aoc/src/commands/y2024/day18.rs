@@ -0,0 +1,154 @@
+use clap::Parser;
+
+use log::debug;
+use nom::bytes::complete::tag;
+use nom::character::complete::digit1;
+use nom::character::complete::line_ending;
+use nom::combinator::map_res;
+use nom::multi::separated_list1;
+use nom::sequence::separated_pair;
+use nom::IResult;
+use rand::seq::SliceRandom;
+use std::collections::HashSet;
+use std::num::NonZeroUsize;
+
+use crate::commands::{DayArgs, DynError, Parts, Puzzle, Registration, Solution, Solver};
+use crate::utils::grid::Grid;
+use crate::utils::random::InputRng;
+use crate::utils::search::{bfs, Neighbors};
+use crate::utils::{from_nom, AocError, ParseError};
+
+#[derive(Parser, Debug)]
+pub struct Day18 {
+    #[clap(flatten)]
+    args: DayArgs,
+    /// Side of the memory space, counted in bytes
+    #[clap(long, default_value_t = SIZE)]
+    size: NonZeroUsize,
+    /// How many of the bytes have fallen by the time part one is solved
+    #[clap(long, default_value_t = FALLEN)]
+    bytes: usize,
+}
+
+impl Default for Day18 {
+    fn default() -> Self {
+        Self { args: DayArgs::default(), size: SIZE, bytes: FALLEN }
+    }
+}
+
+inventory::submit! {
+    Registration::new::<Day18>(Puzzle {
+        year: 2024,
+        day: 18,
+        title: "RAM Run",
+        parts: Parts::One,
+        input: "day18",
+    })
+}
+
+/// The side of the memory space in the puzzle input, which the example shrinks.
+const SIZE: NonZeroUsize = NonZeroUsize::new(71).unwrap();
+
+/// How many bytes part one lets fall in the puzzle input.
+const FALLEN: usize = 1024;
+
+fn my_digit(input: &str) -> IResult<&str, usize> {
+    map_res(digit1, str::parse)(input)
+}
+
+fn parse_command(input: &str) -> IResult<&str, Point> {
+    debug!("parse_command: {input}");
+    let (input, command) = separated_pair(my_digit, tag(","), my_digit)(input)?;
+    Ok((input, command))
+}
+
+pub fn parse_corrupted_bytes(input: &str) -> IResult<&str, Vec<Point>> {
+    debug!("parse_corrupted_bytes: {input}");
+    let (input, corrupted_bytes) = separated_list1(line_ending, parse_command)(input)?;
+    Ok((input, corrupted_bytes))
+}
+
+pub type Point = (usize, usize);
+
+#[derive(Debug)]
+pub struct Maze {
+    corrupted: Grid<bool>,
+}
+
+impl Maze {
+    /// The memory space `size` bytes on each side, with `barriers` corrupted.
+    pub fn new(size: usize, barriers: Vec<Point>) -> Self {
+        let mut corrupted: Grid<bool> = Grid::filled(size, size, false);
+        for barrier in barriers {
+            corrupted[barrier] = true;
+        }
+        Maze { corrupted }
+    }
+
+    pub fn shortest_path(&self) -> Option<usize> {
+        let (nrows, ncols) = self.corrupted.dimensions();
+        bfs(self, [(0, 0)]).distance(&(nrows - 1, ncols - 1))
+    }
+}
+
+impl Neighbors for Maze {
+    type Node = Point;
+
+    fn neighbors(&self, p: &Point) -> impl IntoIterator<Item = Point> {
+        self.corrupted.neighbors4(*p).filter(|&n| !self.corrupted[n])
+    }
+}
+
+impl Solver for Day18 {
+    type Parsed = Vec<Point>;
+
+    fn args(&self) -> &DayArgs {
+        &self.args
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, DynError> {
+        debug!("main");
+        let bytes = from_nom(input, parse_corrupted_bytes(input))?;
+        let size = self.size.get();
+        if let Some((x, y)) = bytes.iter().find(|&&(x, y)| x >= size || y >= size) {
+            let msg = format!("byte {x},{y} falls outside the {size}x{size} memory space");
+            return Err(ParseError::new(msg).into());
+        }
+        Ok(bytes)
+    }
+
+    fn part1(&self, corrupted_bytes: &Self::Parsed) -> Result<Solution, DynError> {
+        let fallen = corrupted_bytes.iter().copied().take(self.bytes).collect();
+        let maze: Maze = Maze::new(self.size.get(), fallen);
+        if let Some(steps) = maze.shortest_path() {
+            Ok(Solution::new().with_part1(steps))
+        } else {
+            Err(AocError::NoSolution("unable to find path".to_string()).into())
+        }
+    }
+
+    fn generate(&self, rng: &mut InputRng, size: Option<usize>) -> Option<String> {
+        let side = size.unwrap_or(SIZE.get()).max(5);
+        // Keep a random staircase from corner to corner clear until every other byte has fallen,
+        // so part one always has a way through.
+        let mut moves: Vec<bool> = [vec![true; side - 1], vec![false; side - 1]].concat();
+        moves.shuffle(rng);
+        let mut path: HashSet<Point> = HashSet::from([(0, 0)]);
+        let mut p: Point = (0, 0);
+        for right in moves {
+            p = if right { (p.0, p.1 + 1) } else { (p.0 + 1, p.1) };
+            path.insert(p);
+        }
+        let mut bytes: Vec<Point> = Vec::new();
+        for x in 0..side {
+            for y in 0..side {
+                if !path.contains(&(x, y)) {
+                    bytes.push((x, y));
+                }
+            }
+        }
+        bytes.shuffle(rng);
+        let bytes = bytes.into_iter().take(side * side * 2 / 3);
+        Some(bytes.map(|(x, y)| format!("{x},{y}\n")).collect())
+    }
+}
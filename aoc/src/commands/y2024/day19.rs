@@ -0,0 +1,186 @@
+use clap::Parser;
+use log::debug;
+
+use nom::bytes::complete::tag;
+use nom::character::complete::alpha1;
+use nom::character::complete::line_ending;
+use nom::multi::many1;
+use nom::multi::separated_list1;
+use nom::IResult;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+use crate::commands::{DayArgs, DynError, Parts, Puzzle, Registration, Solution, Solver};
+use crate::utils::from_nom;
+use crate::utils::random::InputRng;
+
+#[derive(Parser, Debug, Default)]
+pub struct Day19 {
+    #[clap(flatten)]
+    args: DayArgs,
+}
+
+inventory::submit! {
+    Registration::new::<Day19>(Puzzle {
+        year: 2024,
+        day: 19,
+        title: "Linen Layout",
+        parts: Parts::One,
+        input: "day19",
+    })
+}
+
+pub enum Stripe {
+    White,
+    Blue,
+    Black,
+    Red,
+    Green,
+}
+
+impl Stripe {
+    pub fn new(patterns: &str) -> Vec<Stripe> {
+        patterns
+            .chars()
+            .filter_map(|c| match c {
+                'w' => Some(Stripe::White),
+                'u' => Some(Stripe::Blue),
+                'b' => Some(Stripe::Black),
+                'r' => Some(Stripe::Red),
+                'g' => Some(Stripe::Green),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+pub type Towel = Vec<Stripe>;
+
+fn parse_available_towel_patterns(input: &str) -> IResult<&str, HashSet<&str>> {
+    let (input, patterns) = separated_list1(tag(", "), alpha1)(input)?;
+    let patterns: HashSet<&str> = HashSet::from_iter(patterns.iter().copied());
+    Ok((input, patterns))
+}
+
+fn parse_towel_designs(input: &str) -> IResult<&str, Vec<&str>> {
+    let (input, patterns) = separated_list1(line_ending, alpha1)(input)?;
+    Ok((input, patterns))
+}
+
+pub fn parse_challenge(input: &str) -> IResult<&str, (HashSet<&str>, Vec<&str>)> {
+    let (input, available_patterns) = parse_available_towel_patterns(input)?;
+    let (input, _) = many1(line_ending)(input)?;
+    let (input, displayed_patterns) = parse_towel_designs(input)?;
+    Ok((input, (available_patterns, displayed_patterns)))
+}
+
+#[derive(Debug)]
+pub struct PatternBuilder<'a> {
+    patterns: HashSet<&'a str>,
+}
+
+pub trait Designable<'a> {
+    fn designable(&self, design: &'a str) -> bool;
+}
+
+impl<'a> PatternBuilder<'a> {
+    fn new(patterns: HashSet<&'a str>) -> Self {
+        Self { patterns }
+    }
+}
+
+impl<'a> Designable<'a> for PatternBuilder<'a> {
+    fn designable(&self, design: &'a str) -> bool {
+        let mut queue: VecDeque<usize> = VecDeque::new();
+        let string = design.to_string();
+        // Whether the design has been reached up to each offset, so each is searched from once.
+        let mut seen: Vec<bool> = vec![false; string.len() + 1];
+        queue.push_back(0usize);
+        while let Some(offset) = queue.pop_front() {
+            if offset == string.len() {
+                return true;
+            }
+            if offset > string.len() || std::mem::replace(&mut seen[offset], true) {
+                continue;
+            }
+            for prefix in self.patterns.iter() {
+                if string[offset..].starts_with(prefix) {
+                    let new_offset: usize = offset + prefix.len();
+                    if new_offset <= string.len() {
+                        queue.push_back(offset + prefix.len());
+                    }
+                }
+            }
+        }
+        false
+    }
+}
+
+impl Solver for Day19 {
+    type Parsed = (HashSet<String>, Vec<String>);
+
+    fn args(&self) -> &DayArgs {
+        &self.args
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, DynError> {
+        let (patterns, designs) = from_nom(input, parse_challenge(input))?;
+        let patterns: HashSet<String> = patterns.iter().map(|x| x.to_string()).collect();
+        let designs: Vec<String> = designs.iter().map(|x| x.to_string()).collect();
+        Ok((patterns, designs))
+    }
+
+    fn part1(&self, (patterns, designs): &Self::Parsed) -> Result<Solution, DynError> {
+        let mut patterns: HashSet<&str> = patterns.iter().map(|x| x.as_str()).collect();
+        let npatterns = patterns.len();
+        // Patterns that the shorter ones can make up add nothing.
+        let smaller_patterns: HashSet<&str> =
+            HashSet::from_iter(patterns.iter().filter(|&x| x.len() <= 2).copied());
+        let pattern_builder = PatternBuilder::new(smaller_patterns);
+        patterns.retain(|&x| x.len() <= 2 || !pattern_builder.designable(x));
+
+        let diagnostic = format!("shrunk {:?} patterns to {:?}", npatterns, patterns.len());
+        debug!("shrunk to {:?}", patterns);
+        let pattern_builder = PatternBuilder::new(patterns);
+        let mut ndesignable: usize = 0;
+        for design in designs.iter() {
+            if pattern_builder.designable(design) {
+                ndesignable += 1;
+            }
+        }
+        Ok(Solution::new().with_part1(ndesignable).with_diagnostic(diagnostic))
+    }
+
+    fn generate(&self, rng: &mut InputRng, size: Option<usize>) -> Option<String> {
+        let stripes = |rng: &mut InputRng, len: usize| -> String {
+            (0..len).map(|_| ['w', 'u', 'b', 'r', 'g'][rng.gen_range(0..5)]).collect()
+        };
+        let mut patterns: Vec<String> = Vec::new();
+        while patterns.len() < 447 {
+            let len = rng.gen_range(1..=8);
+            let pattern = stripes(rng, len);
+            if !patterns.contains(&pattern) {
+                patterns.push(pattern);
+            }
+        }
+        // Build most designs out of patterns, and leave the rest to chance.
+        let designs: Vec<String> = (0..size.unwrap_or(400))
+            .map(|_| {
+                if rng.gen_bool(0.7) {
+                    let npatterns = rng.gen_range(3..=10);
+                    (0..npatterns)
+                        .filter_map(|_| patterns.choose(rng))
+                        .map(String::as_str)
+                        .collect()
+                } else {
+                    let len = rng.gen_range(20..=60);
+                    stripes(rng, len)
+                }
+            })
+            .collect();
+        Some(format!("{}\n\n{}\n", patterns.join(", "), designs.join("\n")))
+    }
+}
@@ -0,0 +1,109 @@
+use clap::Parser;
+use rand::Rng;
+
+use crate::utils::slurp_str;
+
+use crate::commands::{DayArgs, DynError, Parts, Puzzle, Registration, Solution, Solver};
+use crate::utils::random::InputRng;
+
+#[derive(Parser, Debug, Default)]
+pub struct Day2 {
+    #[clap(flatten)]
+    args: DayArgs,
+}
+
+inventory::submit! {
+    Registration::new::<Day2>(Puzzle {
+        year: 2024,
+        day: 2,
+        title: "Red-Nosed Reports",
+        parts: Parts::Both,
+        input: "day2",
+    })
+}
+
+pub fn differences(vec: &[i32]) -> Vec<i32> {
+    vec.windows(2).map(|w| w[1] - w[0]).collect()
+}
+
+pub fn valid(vec: &[i32]) -> bool {
+    let diffs: Vec<i32> = differences(vec);
+    let all_negative: bool = diffs.iter().all(|x| *x < 0);
+    let all_positive: bool = diffs.iter().all(|x| *x > 0);
+    let all_in_range: bool = diffs.iter().all(|x| x.abs() < 4);
+    all_in_range && (all_negative || all_positive)
+}
+
+pub fn is_valid(vec: &[i32]) -> bool {
+    if valid(vec) {
+        return true;
+    }
+    for i in 0..vec.len() {
+        let left_slice: &[i32] = &vec[0..i];
+        let right_slice: &[i32] = if i < vec.len() - 1 { &vec[(i + 1)..] } else { &[] };
+        let new_slice = [left_slice, right_slice].concat();
+        if valid(&new_slice) {
+            return true;
+        }
+    }
+    false
+}
+
+impl Solver for Day2 {
+    type Parsed = Vec<Vec<i32>>;
+
+    fn args(&self) -> &DayArgs {
+        &self.args
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, DynError> {
+        let lines: Vec<String> = slurp_str(input)?;
+        let reports: Vec<Vec<i32>> =
+            lines.iter().map(|s| s.split(' ').flat_map(|x| x.parse::<i32>()).collect()).collect();
+        Ok(reports)
+    }
+
+    fn part1(&self, reports: &Self::Parsed) -> Result<Solution, DynError> {
+        let n_valid: usize = reports.iter().filter(|x| valid(x)).count();
+
+        Ok(Solution::new().with_part1(n_valid))
+    }
+
+    fn part2(&self, reports: &Self::Parsed) -> Result<Solution, DynError> {
+        //println!("reports: {:?}", reports);
+        let n_valid: usize = reports.iter().map(|x| is_valid(x)).filter(|&x| x).count();
+
+        Ok(Solution::new().with_part2(n_valid))
+    }
+
+    fn generate(&self, rng: &mut InputRng, size: Option<usize>) -> Option<String> {
+        let mut input = String::new();
+        for _ in 0..size.unwrap_or(1000) {
+            let direction: i32 = if rng.gen_bool(0.5) { 1 } else { -1 };
+            let mut level: i32 = rng.gen_range(10..90);
+            let mut levels: Vec<i32> = vec![level];
+            // Most reports are safe; some get one bad step, which the dampener may forgive.
+            let bad_step = rng.gen_bool(0.4).then(|| rng.gen_range(1..8));
+            for i in 1..rng.gen_range(5..=8) {
+                let step =
+                    if bad_step == Some(i) { rng.gen_range(-6..=6) } else { rng.gen_range(1..=3) };
+                level += direction * step;
+                levels.push(level);
+            }
+            let levels: Vec<String> = levels.iter().map(i32::to_string).collect();
+            input.push_str(&levels.join(" "));
+            input.push('\n');
+        }
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_slices() {
+        assert!(is_valid(&[80, 81, 83, 84, 87]));
+    }
+}
@@ -0,0 +1,244 @@
+use clap::Parser;
+use log::debug;
+
+use itertools::Itertools;
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::commands::{DayArgs, DynError, Parts, Puzzle, Registration, Solution, Solver};
+use crate::utils::geometry;
+use crate::utils::grid::Grid;
+use crate::utils::random::{self, InputRng};
+use crate::utils::search::{bfs, Neighbors, Search};
+
+#[derive(Parser, Debug)]
+pub struct Day20 {
+    #[clap(flatten)]
+    args: DayArgs,
+    /// Count only cheats that save at least this many picoseconds
+    #[clap(long, default_value_t = MIN_SAVING)]
+    min_saving: usize,
+}
+
+impl Default for Day20 {
+    fn default() -> Self {
+        Self { args: DayArgs::default(), min_saving: MIN_SAVING }
+    }
+}
+
+inventory::submit! {
+    Registration::new::<Day20>(Puzzle {
+        year: 2024,
+        day: 20,
+        title: "Race Condition",
+        parts: Parts::Two,
+        input: "day20",
+    })
+}
+
+pub type Point = (usize, usize);
+
+/// The saving the puzzle asks about, in picoseconds.
+const MIN_SAVING: usize = 100;
+
+/// How long a cheat can last, in picoseconds.
+const CHEAT_DURATION: usize = 20;
+
+pub trait Cheatable {
+    type Item;
+    fn cheats(
+        &self,
+        nrows: usize,
+        ncols: usize,
+        cheat_duration: usize,
+    ) -> impl Iterator<Item = Self::Item>;
+}
+
+impl Cheatable for Point {
+    type Item = Point;
+
+    fn cheats(
+        &self,
+        nrows: usize,
+        ncols: usize,
+        cheat_duration: usize,
+    ) -> impl Iterator<Item = Point> {
+        let minrow: usize = self.0.saturating_sub(cheat_duration);
+        let mincol: usize = self.1.saturating_sub(cheat_duration);
+        let maxrow: usize =
+            if nrows - self.0 - 1 < cheat_duration { nrows - 1 } else { self.0 + cheat_duration };
+        let maxcol: usize =
+            if ncols - self.1 - 1 < cheat_duration { ncols - 1 } else { self.1 + cheat_duration };
+
+        (minrow..=maxrow).cartesian_product(mincol..=maxcol)
+    }
+}
+
+pub fn find_start(maze: &Grid<Object>) -> Option<Point> {
+    maze.find(&Object::Start)
+}
+
+pub fn find_end(maze: &Grid<Object>) -> Option<Point> {
+    maze.find(&Object::End)
+}
+
+#[derive(Default, Copy, Clone, Hash, PartialEq)]
+pub enum Object {
+    #[default]
+    Space,
+    Start,
+    End,
+    Wall,
+}
+
+impl Object {
+    pub fn new(tick: char) -> Option<Self> {
+        match tick {
+            '.' => Some(Object::Space),
+            'S' => Some(Object::Start),
+            'E' => Some(Object::End),
+            '#' => Some(Object::Wall),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Debug for Object {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Object::Wall => write!(f, "Wall "),
+            Object::End => write!(f, "End  "),
+            Object::Start => write!(f, "Start"),
+            Object::Space => write!(f, "Space"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Maze {
+    blueprint: Grid<Object>,
+    start: Point,
+    end: Point,
+}
+
+impl Maze {
+    pub fn new(blueprint: Grid<Object>, start: Point, end: Point) -> Self {
+        Maze { blueprint, start, end }
+    }
+
+    pub fn reachable(&self, p: Point) -> bool {
+        self.blueprint[p] != Object::Wall
+    }
+
+    pub fn nrows(&self) -> usize {
+        self.blueprint.nrows()
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.blueprint.ncols()
+    }
+}
+
+impl Neighbors for Maze {
+    type Node = Point;
+
+    fn neighbors(&self, p: &Point) -> impl IntoIterator<Item = Point> {
+        self.blueprint.neighbors4(*p).filter(|&n| self.reachable(n))
+    }
+}
+
+#[derive(Debug)]
+pub struct ShortestPath {
+    maze: Maze,
+}
+
+impl ShortestPath {
+    pub fn new(maze: Maze) -> Self {
+        Self { maze }
+    }
+}
+
+impl ShortestPath {
+    pub fn search(&self, current_score: usize) -> usize {
+        let search = bfs(&self.maze, [self.maze.start]);
+        search.distance(&self.maze.end).map_or(usize::MAX, |steps| current_score + steps)
+    }
+
+    /// Steps from each square to the end, with `usize::MAX` for walls.
+    fn distance_matrix(&self, from_end: &Search<Point>) -> Grid<usize> {
+        let mut dm: Grid<usize> = Grid::filled(self.maze.nrows(), self.maze.ncols(), usize::MAX);
+        for (&point, &steps) in from_end.distances() {
+            dm[point] = steps;
+        }
+        dm
+    }
+
+    fn tally_cheats(&self, dm: &Grid<usize>, track: &[Point]) -> HashMap<usize, usize> {
+        let nrows: usize = self.maze.nrows();
+        let ncols: usize = self.maze.ncols();
+        let mut improvements: HashMap<usize, usize> = HashMap::new();
+        for &point in track {
+            let nsteps: usize = dm[point];
+            for cheat in point.cheats(nrows, ncols, CHEAT_DURATION) {
+                let child_steps: usize = dm[cheat];
+                let distance = geometry::Point::from(point).manhattan(cheat.into());
+                // The cheats come from a square around the point, so the corners are too far.
+                if distance <= CHEAT_DURATION && child_steps.saturating_add(distance) < nsteps {
+                    let diff: usize = nsteps - child_steps - distance;
+                    *improvements.entry(diff).or_insert(0usize) += 1;
+                }
+            }
+        }
+        improvements
+    }
+}
+
+impl Solver for Day20 {
+    type Parsed = Grid<Object>;
+
+    fn args(&self) -> &DayArgs {
+        &self.args
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, DynError> {
+        Ok(Grid::parse(input, Object::new)?)
+    }
+
+    fn part2(&self, rows: &Self::Parsed) -> Result<Solution, DynError> {
+        if let (Some(start), Some(end)) = (find_start(rows), find_end(rows)) {
+            let maze: Maze = Maze::new(rows.clone(), start, end);
+            let shortest_path: ShortestPath = ShortestPath::new(maze);
+            let best_score = shortest_path.search(0usize);
+            let diagnostic = format!("shortest path: {best_score}");
+            let from_end: Search<Point> = bfs(&shortest_path.maze, [end]);
+            let dm: Grid<usize> = shortest_path.distance_matrix(&from_end);
+            let track: Vec<Point> = from_end.path_to(&start).unwrap_or_default();
+            let cheatmap: HashMap<usize, usize> = shortest_path.tally_cheats(&dm, &track);
+            let mut n: usize = 0usize;
+            debug!("cheats {:?}", cheatmap);
+            for (k, v) in cheatmap.into_iter() {
+                if k >= self.min_saving {
+                    n += v;
+                }
+            }
+            return Ok(Solution::new().with_part2(n).with_diagnostic(diagnostic));
+        }
+
+        Ok(Solution::new())
+    }
+
+    fn generate(&self, rng: &mut InputRng, size: Option<usize>) -> Option<String> {
+        let side = size.unwrap_or(141).max(5) | 1;
+        // A race has one track with no branches, so keep only the longest corridor of a maze.
+        let open = random::perfect_maze(rng, side, side);
+        let track = random::longest_corridor(&open, (1, 1));
+        let mut racetrack: Grid<char> = Grid::filled(side, side, '#');
+        for &p in track.iter() {
+            racetrack[p] = '.';
+        }
+        racetrack[track[0]] = 'S';
+        racetrack[track[track.len() - 1]] = 'E';
+        Some(format!("{racetrack}\n"))
+    }
+}
@@ -1,22 +1,34 @@
-use std::path::PathBuf;
-
 use clap::Parser;
+use log::debug;
 use std::collections::VecDeque;
 
-use env_logger;
-use nom::character::complete::alphanumeric1;
 use nom::character::complete::line_ending;
+use nom::character::complete::one_of;
+use nom::combinator::map;
 use nom::multi::many1;
 use nom::multi::separated_list1;
 use nom::IResult;
-use std::fs;
+use rand::Rng;
 
-use super::{CommandImpl, DynError};
+use crate::commands::{DayArgs, DynError, Parts, Puzzle, Registration, Solution, Solver};
+use crate::utils::from_nom;
+use crate::utils::geometry::Direction;
+use crate::utils::random::InputRng;
 
-#[derive(Parser, Debug)]
+#[derive(Parser, Debug, Default)]
 pub struct Day21 {
-    #[clap(long, short)]
-    input: PathBuf,
+    #[clap(flatten)]
+    args: DayArgs,
+}
+
+inventory::submit! {
+    Registration::new::<Day21>(Puzzle {
+        year: 2024,
+        day: 21,
+        title: "Keypad Conundrum",
+        parts: Parts::One,
+        input: "day21",
+    })
 }
 
 pub trait Positional {
@@ -25,7 +37,7 @@ pub trait Positional {
 }
 
 impl DirectionalKey {
-    fn move_steps(&self) -> (i32, i32) {
+    pub fn move_steps(&self) -> (i32, i32) {
         match self {
             DirectionalKey::Activate => (0i32, 0i32),
             DirectionalKey::Right => (0i32, 1i32),
@@ -36,7 +48,7 @@ impl DirectionalKey {
         }
     }
 
-    fn move_arm(&mut self, other: &DirectionalKey) -> DirectionalKey {
+    pub fn move_arm(&mut self, other: &DirectionalKey) -> DirectionalKey {
         let steps = other.move_steps();
         let position = self.position();
         (position.0 + steps.0, position.1 + steps.1).into()
@@ -57,114 +69,108 @@ impl From<KeyLocation> for DirectionalKey {
 }
 
 pub trait Distanceable {
-    fn relative_distance(&self, other: &Self) -> (Direction, Direction);
-    fn vertical(&self, other: &Self) -> Direction;
-    fn horizontal(&self, other: &Self) -> Direction;
+    fn relative_distance(&self, other: &Self) -> (Move, Move);
+    fn vertical(&self, other: &Self) -> Move;
+    fn horizontal(&self, other: &Self) -> Move;
 }
 
 pub trait Directional {
-    fn direction(&self) -> (Direction, Direction);
+    fn direction(&self) -> (Move, Move);
 }
 
 pub trait Traversable {
     // define a safe traversal between nodes
-    fn traverse(&self, other: &Self) -> (Direction, Direction);
+    fn traverse(&self, other: &Self) -> (Move, Move);
 }
 
-#[derive(Debug, PartialEq)]
-pub enum Direction {
-    Right(i32),
-    Down(i32),
-    Left(i32),
-    Up(i32),
-}
+/// Pressing one arrow key a number of times.
+pub type Move = (Direction, i32);
 
 impl Distanceable for DirectionalKey {
-    fn relative_distance(&self, other: &Self) -> (Direction, Direction) {
+    fn relative_distance(&self, other: &Self) -> (Move, Move) {
         (self.vertical(other), self.horizontal(other))
     }
 
-    fn vertical(&self, other: &Self) -> Direction {
+    fn vertical(&self, other: &Self) -> Move {
         let this = self.position();
         let that = other.position();
         if this.0 > that.0 {
-            Direction::Down(this.0.abs_diff(that.0).try_into().unwrap())
+            (Direction::South, this.0.abs_diff(that.0).try_into().unwrap())
         } else {
-            Direction::Up(this.0.abs_diff(that.0).try_into().unwrap())
+            (Direction::North, this.0.abs_diff(that.0).try_into().unwrap())
         }
     }
 
-    fn horizontal(&self, other: &Self) -> Direction {
+    fn horizontal(&self, other: &Self) -> Move {
         let this = self.position();
         let that = other.position();
         if this.1 > that.1 {
-            Direction::Left(this.1.abs_diff(that.1).try_into().unwrap())
+            (Direction::West, this.1.abs_diff(that.1).try_into().unwrap())
         } else {
-            Direction::Right(this.1.abs_diff(that.1).try_into().unwrap())
+            (Direction::East, this.1.abs_diff(that.1).try_into().unwrap())
         }
     }
 }
 
-impl Traversable for DirectionalKey {
-    fn traverse(&self, other: &Self) -> (Direction, Direction) {
-        let distance = self.relative_distance(other);
-        let curr = self.position();
-        let gap = DirectionalKey::Gap.position();
+/// The moves from `from` to `to` in the order they are pressed, never crossing the gap.
+///
+/// Moving left first, and right last, keeps the robots pressing the same arrow in a row, which
+/// is what makes the code shortest once it is typed through the keypads above. A move along one
+/// axis only comes out vertical first.
+fn ordered<K>(from: &K, to: &K) -> (Move, Move)
+where
+    K: Distanceable + Positional<Position = KeyLocation> + Avoidable<Position = KeyLocation>,
+{
+    let (vertical, horizontal) = from.relative_distance(to);
+    let (start, end, gap) = (from.position(), to.position(), from.avoided_position());
+    let left_first = horizontal.0 == Direction::West && horizontal.1 > 0 && vertical.1 > 0;
+    let horizontal_first =
+        if left_first { (start.0, end.1) != gap } else { (end.0, start.1) == gap };
+    if horizontal_first {
+        (horizontal, vertical)
+    } else {
+        (vertical, horizontal)
+    }
+}
 
-        if curr.0 == gap.0 {
-            (distance.1, distance.0)
-        } else {
-            distance
-        }
+impl Traversable for DirectionalKey {
+    fn traverse(&self, other: &Self) -> (Move, Move) {
+        ordered(self, other)
     }
 }
 
 impl Traversable for NumericalKey {
-    fn traverse(&self, other: &Self) -> (Direction, Direction) {
-        let distance = self.relative_distance(other);
-        let curr = self.position();
-        let gap = DirectionalKey::Gap.position();
-
-        if curr.0 == gap.0 {
-            (distance.1, distance.0)
-        } else {
-            distance
-        }
+    fn traverse(&self, other: &Self) -> (Move, Move) {
+        ordered(self, other)
     }
 }
 
-fn translate(d: Direction) -> String {
-    let (c, n): (char, i32) = match d {
-        Direction::Right(n) => ('>', n),
-        Direction::Down(n) => ('v', n),
-        Direction::Left(n) => ('<', n),
-        Direction::Up(n) => ('^', n),
-    };
-    (0..n).map(|_| c).collect::<String>()
+fn translate((d, n): Move) -> String {
+    (0..n).map(|_| d.arrow()).collect::<String>()
 }
 
 impl Distanceable for NumericalKey {
-    fn relative_distance(&self, other: &Self) -> (Direction, Direction) {
+    fn relative_distance(&self, other: &Self) -> (Move, Move) {
         (self.vertical(other), self.horizontal(other))
     }
 
-    fn vertical(&self, other: &Self) -> Direction {
+    fn vertical(&self, other: &Self) -> Move {
         let this = self.position();
         let that = other.position();
         if this.0 > that.0 {
-            Direction::Down(this.0.abs_diff(that.0).try_into().unwrap())
+            (Direction::South, this.0.abs_diff(that.0).try_into().unwrap())
         } else {
-            Direction::Up(this.0.abs_diff(that.0).try_into().unwrap())
+            (Direction::North, this.0.abs_diff(that.0).try_into().unwrap())
         }
     }
 
-    fn horizontal(&self, other: &Self) -> Direction {
+    fn horizontal(&self, other: &Self) -> Move {
         let this = self.position();
         let that = other.position();
         if this.1 > that.1 {
-            Direction::Left(this.1.abs_diff(that.1).try_into().unwrap())
+            (Direction::West, this.1.abs_diff(that.1).try_into().unwrap())
         } else {
-            Direction::Right(this.1.abs_diff(that.1).try_into().unwrap())
+            (Direction::East, this.1.abs_diff(that.1).try_into().unwrap())
         }
     }
 }
@@ -340,15 +346,15 @@ impl TryFrom<NumericalKey> for i32 {
 }
 
 fn parse_command(input: &str) -> IResult<&str, Vec<NumericalKey>> {
-    let (input, code) = alphanumeric1(input)?;
-    let mut code: VecDeque<NumericalKey> = code.chars().map(|c| c.into()).collect();
+    let (input, code) = many1(map(one_of("0123456789A"), NumericalKey::from))(input)?;
+    let mut code: VecDeque<NumericalKey> = code.into();
     code.push_front(NumericalKey::Activate);
     let vec: Vec<NumericalKey> = code.into();
 
     Ok((input, vec))
 }
 
-fn parse_challenge(input: &str) -> IResult<&str, Vec<Vec<NumericalKey>>> {
+pub fn parse_challenge(input: &str) -> IResult<&str, Vec<Vec<NumericalKey>>> {
     let (input, codes) = separated_list1(many1(line_ending), parse_command)(input)?;
     Ok((input, codes))
 }
@@ -356,10 +362,10 @@ fn parse_challenge(input: &str) -> IResult<&str, Vec<Vec<NumericalKey>>> {
 fn decode<D: Traversable>(code: &[D]) -> String {
     let mut s: String = "A".to_string();
     for commands in code.windows(2) {
-        let d: (Direction, Direction) = commands[0].traverse(&commands[1]);
-        s.push_str(&translate(d.1).to_owned());
+        let d: (Move, Move) = commands[0].traverse(&commands[1]);
         s.push_str(&translate(d.0).to_owned());
-        s.push_str("A");
+        s.push_str(&translate(d.1).to_owned());
+        s.push('A');
     }
     s
 }
@@ -371,7 +377,7 @@ fn decode<D: Traversable>(code: &[D]) -> String {
 //        let mut curr = D::default();
 //
 //        for commands in code.chars() {
-//            let d: (Direction, Direction) = curr.traverse(&commands);
+//            let d: (Move, Move) = curr.traverse(&commands);
 //            s.push_str(&translate(d.1).to_owned());
 //            s.push_str(&translate(d.0).to_owned());
 //            s.push_str(&"A".to_owned());
@@ -380,41 +386,58 @@ fn decode<D: Traversable>(code: &[D]) -> String {
 //    }
 //}
 
-impl CommandImpl for Day21 {
-    fn main(&self) -> Result<(), DynError> {
-        env_logger::init();
-        let blob_string = fs::read_to_string(&self.input)?;
+impl Solver for Day21 {
+    type Parsed = Vec<Vec<NumericalKey>>;
+
+    fn args(&self) -> &DayArgs {
+        &self.args
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, DynError> {
+        Ok(from_nom(input, parse_challenge(input))?)
+    }
+
+    fn part1(&self, codes: &Self::Parsed) -> Result<Solution, DynError> {
+        let mut solution = Solution::new();
         let mut part1_answer: i32 = 0;
-        match parse_challenge(&blob_string) {
-            Ok((_, codes)) => {
-                for code in codes.iter() {
-                    let numerical_instructions: String = decode(code);
-                    let ch: Vec<char> = numerical_instructions.chars().collect();
-                    let directions: Vec<DirectionalKey> =
-                        ch.iter().map(|&c| DirectionalKey::from(c)).collect();
-                    let robot_2: String = decode(&directions);
-                    let ch: Vec<char> = robot_2.chars().collect();
-                    let directions: Vec<DirectionalKey> =
-                        ch.iter().map(|&c| DirectionalKey::from(c)).collect();
-                    let robot_1: String = decode(&directions);
-                    if let Some(numeric_value) = NumericalKey::numeric(code) {
-                        println!(
-                            "complexity = {:?} * {:?}",
-                            (robot_1.len() as i32 - 1_i32),
-                            numeric_value
-                        );
-                        part1_answer += numeric_value * (robot_1.len() as i32 - 1_i32);
-                    }
-                    println!("human1 to robot 1: {robot_1}");
-                    println!("robot1 to robot 2: {robot_2}");
-                    println!("robot2 to robot 3: {numerical_instructions}");
-                    println!("code: {:?}", code);
-                }
-                println!("part 1 answer: {part1_answer}");
+        for code in codes.iter() {
+            let numerical_instructions: String = decode(code);
+            let ch: Vec<char> = numerical_instructions.chars().collect();
+            let directions: Vec<DirectionalKey> =
+                ch.iter().map(|&c| DirectionalKey::from(c)).collect();
+            let robot_2: String = decode(&directions);
+            let ch: Vec<char> = robot_2.chars().collect();
+            let directions: Vec<DirectionalKey> =
+                ch.iter().map(|&c| DirectionalKey::from(c)).collect();
+            let robot_1: String = decode(&directions);
+            if let Some(numeric_value) = NumericalKey::numeric(code) {
+                solution = solution.with_diagnostic(format!(
+                    "complexity = {:?} * {:?}",
+                    (robot_1.len() as i32 - 1_i32),
+                    numeric_value
+                ));
+                part1_answer += numeric_value * (robot_1.len() as i32 - 1_i32);
             }
-            Err(error) => panic!("Problem opening the file: {error:?}"),
-        };
-        Ok(())
+            debug!("human1 to robot 1: {robot_1}");
+            debug!("robot1 to robot 2: {robot_2}");
+            debug!("robot2 to robot 3: {numerical_instructions}");
+            debug!("code: {:?}", code);
+        }
+        Ok(solution.with_part1(part1_answer))
+    }
+
+    fn generate(&self, rng: &mut InputRng, size: Option<usize>) -> Option<String> {
+        let codes: String = (0..size.unwrap_or(5))
+            .map(|_| {
+                format!(
+                    "{}{}{}A\n",
+                    rng.gen_range(0..10),
+                    rng.gen_range(0..10),
+                    rng.gen_range(0..10)
+                )
+            })
+            .collect();
+        Some(codes)
     }
 }
 
@@ -426,13 +449,13 @@ mod test {
     fn test_numerical_distance() -> Result<(), Box<dyn std::error::Error>> {
         let lhs: NumericalKey = NumericalKey::Zero;
         let rhs: NumericalKey = NumericalKey::Eight;
-        let (row, column): (Direction, Direction) = lhs.traverse(&rhs);
-        assert_eq!(row, Direction::Up(3i32));
-        assert_eq!(column, Direction::Right(0i32));
+        let (row, column): (Move, Move) = lhs.traverse(&rhs);
+        assert_eq!(row, (Direction::North, 3i32));
+        assert_eq!(column, (Direction::East, 0i32));
         let rhs: NumericalKey = NumericalKey::One;
-        let (row, column): (Direction, Direction) = lhs.traverse(&rhs);
-        assert_eq!(row, Direction::Up(1i32));
-        assert_eq!(column, Direction::Left(1i32));
+        let (row, column): (Move, Move) = lhs.traverse(&rhs);
+        assert_eq!(row, (Direction::North, 1i32));
+        assert_eq!(column, (Direction::West, 1i32));
         Ok(())
     }
 
@@ -440,13 +463,20 @@ mod test {
     fn test_direction_distance() -> Result<(), Box<dyn std::error::Error>> {
         let lhs: DirectionalKey = DirectionalKey::Activate;
         let rhs: DirectionalKey = DirectionalKey::Left;
-        let (row, column): (Direction, Direction) = lhs.traverse(&rhs);
-        assert_eq!(row, Direction::Down(1i32));
-        assert_eq!(column, Direction::Left(2i32));
+        let (row, column): (Move, Move) = lhs.traverse(&rhs);
+        assert_eq!(row, (Direction::South, 1i32));
+        assert_eq!(column, (Direction::West, 2i32));
         let rhs: DirectionalKey = DirectionalKey::Up;
-        let (row, column): (Direction, Direction) = lhs.traverse(&rhs);
-        assert_eq!(row, Direction::Up(0i32));
-        assert_eq!(column, Direction::Left(1i32));
+        let (row, column): (Move, Move) = lhs.traverse(&rhs);
+        assert_eq!(row, (Direction::North, 0i32));
+        assert_eq!(column, (Direction::West, 1i32));
+        Ok(())
+    }
+    #[test]
+    fn test_part1() -> Result<(), Box<dyn std::error::Error>> {
+        let day = Day21::default();
+        let codes = day.parse("029A\n980A\n179A\n456A\n379A\n")?;
+        assert_eq!(day.part1(&codes)?.part1, Some(126384.into()));
         Ok(())
     }
 }
@@ -1,19 +1,30 @@
-use std::path::PathBuf;
-
 use clap::Parser;
 
 use nom::character::complete::digit1;
 use nom::character::complete::line_ending;
+use nom::combinator::{map, map_res};
 use nom::multi::separated_list1;
 use nom::IResult;
-use std::fs;
+use rand::Rng;
 
-use super::{CommandImpl, DynError};
+use crate::commands::{DayArgs, DynError, Parts, Puzzle, Registration, Solution, Solver};
+use crate::utils::from_nom;
+use crate::utils::random::InputRng;
 
-#[derive(Parser, Debug)]
+#[derive(Parser, Debug, Default)]
 pub struct Day22 {
-    #[clap(long, short)]
-    input: PathBuf,
+    #[clap(flatten)]
+    args: DayArgs,
+}
+
+inventory::submit! {
+    Registration::new::<Day22>(Puzzle {
+        year: 2024,
+        day: 22,
+        title: "Monkey Market",
+        parts: Parts::One,
+        input: "day22",
+    })
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -81,38 +92,47 @@ impl Sequence {
 }
 
 fn my_digit(input: &str) -> IResult<&str, Sequence> {
-    let (input, digits) = digit1(input)?;
-    let x: u64 = digits.parse::<u64>().unwrap();
-    Ok((input, Sequence::One(x)))
+    map(map_res(digit1, str::parse), Sequence::One)(input)
 }
 
-fn parse_numbers(input: &str) -> IResult<&str, Vec<Sequence>> {
+pub fn parse_numbers(input: &str) -> IResult<&str, Vec<Sequence>> {
     let (input, numbers) = separated_list1(line_ending, my_digit)(input)?;
     Ok((input, numbers))
 }
 
-impl CommandImpl for Day22 {
-    fn main(&self) -> Result<(), DynError> {
-        let blob_string = fs::read_to_string(&self.input)?;
-        if let Ok((_, mut numbers)) = parse_numbers(&blob_string) {
-            let results = numbers.iter_mut().map(|x| x.mutate()).collect::<Vec<Sequence>>();
-            let sum: u64 = results.iter().map(|x| x.unwrap()).sum();
-            let max: u64 = results.iter().map(|x| x.unwrap()).max().unwrap();
-            let occurrences: Vec<usize> = results
-                .iter()
-                .enumerate()
-                .map(|(i, x)| (i, x.unwrap()))
-                .filter(|(_, v)| *v == max)
-                .map(|(i, _)| i)
-                .collect::<Vec<usize>>();
-            println!("sum {:?}", sum);
-            println!("the max value is {:?}", max);
-            println!("occurrences: {:?}", occurrences);
-        } else {
-            println!("unable to read {:?}", &self.input);
-        }
+impl Solver for Day22 {
+    type Parsed = Vec<Sequence>;
 
-        Ok(())
+    fn args(&self) -> &DayArgs {
+        &self.args
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, DynError> {
+        Ok(from_nom(input, parse_numbers(input))?)
+    }
+
+    fn part1(&self, numbers: &Self::Parsed) -> Result<Solution, DynError> {
+        let results = numbers.iter().map(|x| x.mutate()).collect::<Vec<Sequence>>();
+        let sum: u64 = results.iter().map(|x| x.unwrap()).sum();
+        let max: u64 = results.iter().map(|x| x.unwrap()).max().unwrap();
+        let occurrences: Vec<usize> = results
+            .iter()
+            .enumerate()
+            .map(|(i, x)| (i, x.unwrap()))
+            .filter(|(_, v)| *v == max)
+            .map(|(i, _)| i)
+            .collect::<Vec<usize>>();
+        Ok(Solution::new()
+            .with_part1(sum)
+            .with_diagnostic(format!("the max value is {:?}", max))
+            .with_diagnostic(format!("occurrences: {:?}", occurrences)))
+    }
+
+    fn generate(&self, rng: &mut InputRng, size: Option<usize>) -> Option<String> {
+        let secrets: String = (0..size.unwrap_or(2000))
+            .map(|_| format!("{}\n", rng.gen_range(1..16777216)))
+            .collect();
+        Some(secrets)
     }
 }
 
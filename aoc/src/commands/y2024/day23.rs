@@ -0,0 +1,153 @@
+use clap::Parser;
+
+use itertools::Itertools;
+use nom::bytes::complete::tag;
+use nom::character::complete::alpha1;
+use nom::character::complete::line_ending;
+use nom::multi::separated_list1;
+use nom::sequence::separated_pair;
+use nom::IResult;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashSet;
+
+use crate::commands::{DayArgs, DynError, Parts, Puzzle, Registration, Solution, Solver};
+use crate::utils::from_nom;
+use crate::utils::random::InputRng;
+
+#[derive(Parser, Debug, Default)]
+pub struct Day23 {
+    #[clap(flatten)]
+    args: DayArgs,
+}
+
+inventory::submit! {
+    Registration::new::<Day23>(Puzzle {
+        year: 2024,
+        day: 23,
+        title: "LAN Party",
+        parts: Parts::One,
+        input: "day23",
+    })
+}
+
+pub type Node = String;
+pub type Vertex = (Node, Node);
+pub type VertexGraph = HashSet<Vertex>;
+
+pub trait Unwrappable {
+    type Item;
+    fn unwrap(&self) -> Vec<Self::Item>;
+}
+
+pub trait Containable {
+    type Item;
+    fn contains(&self, x: Self::Item) -> bool;
+}
+
+pub trait Swappable {
+    fn swap(self) -> Self;
+}
+
+impl Swappable for Vertex {
+    fn swap(self) -> Self {
+        if self.0 < self.1 {
+            self
+        } else {
+            (self.1, self.0)
+        }
+    }
+}
+
+impl Containable for VertexGraph {
+    type Item = Vertex;
+    fn contains(&self, x: Self::Item) -> bool {
+        self.contains(&x)
+    }
+}
+
+impl Unwrappable for Vertex {
+    type Item = Node;
+
+    fn unwrap(&self) -> Vec<Node> {
+        vec![self.0.clone(), self.1.clone()]
+    }
+}
+
+fn parse_connection(input: &str) -> IResult<&str, Vertex> {
+    let (input, (lhs, rhs)) = separated_pair(alpha1, tag("-"), alpha1)(input)?;
+    let (lhs, rhs) = if lhs < rhs { (lhs, rhs) } else { (rhs, lhs) };
+    Ok((input, (lhs.into(), rhs.into())))
+}
+
+pub fn parse_connections(input: &str) -> IResult<&str, VertexGraph> {
+    let (input, mut tuples) = separated_list1(line_ending, parse_connection)(input)?;
+    tuples.sort();
+    let connections: HashSet<Vertex> = HashSet::from_iter(tuples);
+    Ok((input, connections))
+}
+
+impl Solver for Day23 {
+    type Parsed = VertexGraph;
+
+    fn args(&self) -> &DayArgs {
+        &self.args
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, DynError> {
+        Ok(from_nom(input, parse_connections(input))?)
+    }
+
+    fn part1(&self, vertices: &Self::Parsed) -> Result<Solution, DynError> {
+        let commutitive = |x: Node, y: Node, z: Node| -> bool {
+            vertices.contains(&(x.clone(), y.clone()).swap())
+                && vertices.contains(&(x.clone(), z.clone()).swap())
+                && vertices.contains(&(y.clone(), z.clone()).swap())
+        };
+        let nodes = vertices.iter().flat_map(|x| x.unwrap()).collect::<HashSet<Node>>();
+        let ntriplets: usize = nodes
+            .iter()
+            .combinations(3)
+            .filter(|x| x.iter().any(|s| s.starts_with('t')))
+            .filter(|x| commutitive(x[0].to_string(), x[1].to_string(), x[2].to_string()))
+            .count();
+
+        Ok(Solution::new().with_part1(ntriplets))
+    }
+
+    fn generate(&self, rng: &mut InputRng, size: Option<usize>) -> Option<String> {
+        // Names are two letters, so there can be at most 26 * 26 computers.
+        let mut names: Vec<String> = (b'a'..=b'z')
+            .flat_map(|a| (b'a'..=b'z').map(move |b| format!("{}{}", a as char, b as char)))
+            .collect();
+        names.shuffle(rng);
+        names.truncate(size.unwrap_or(520).clamp(2, names.len()));
+        let mut connections: HashSet<(usize, usize)> = HashSet::new();
+        for i in 0..names.len() {
+            for _ in 0..6 {
+                let j = rng.gen_range(0..names.len());
+                if i != j {
+                    connections.insert((i.min(j), i.max(j)));
+                }
+            }
+        }
+        // Hide one large party in among the random links.
+        let party = rand::seq::index::sample(rng, names.len(), names.len().min(13)).into_vec();
+        for (k, &i) in party.iter().enumerate() {
+            for &j in party[k + 1..].iter() {
+                connections.insert((i.min(j), i.max(j)));
+            }
+        }
+        let mut connections: Vec<(usize, usize)> = connections.into_iter().collect();
+        // A set's order changes from run to run, so sort first to leave the order to the seed.
+        connections.sort();
+        connections.shuffle(rng);
+        let lines: String = connections
+            .into_iter()
+            .map(|(i, j)| if rng.gen_bool(0.5) { (i, j) } else { (j, i) })
+            .map(|(i, j)| format!("{}-{}\n", names[i], names[j]))
+            .collect();
+        Some(lines)
+    }
+}
@@ -1,5 +1,3 @@
-use std::path::PathBuf;
-
 use clap::Parser;
 
 use nom::branch::alt;
@@ -11,18 +9,31 @@ use nom::character::complete::space1;
 use nom::multi::many1;
 use nom::multi::separated_list1;
 use nom::IResult;
-use std::fs;
 
+use rand::seq::SliceRandom;
+use rand::Rng;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
 
-use super::{CommandImpl, DynError};
+use crate::commands::{DayArgs, DynError, Parts, Puzzle, Registration, Solution, Solver};
+use crate::utils::from_nom;
+use crate::utils::random::InputRng;
 
-#[derive(Parser, Debug)]
+#[derive(Parser, Debug, Default)]
 pub struct Day24 {
-    #[clap(long, short)]
-    input: PathBuf,
+    #[clap(flatten)]
+    args: DayArgs,
+}
+
+inventory::submit! {
+    Registration::new::<Day24>(Puzzle {
+        year: 2024,
+        day: 24,
+        title: "Crossed Wires",
+        parts: Parts::One,
+        input: "day24",
+    })
 }
 
 type Node = String;
@@ -79,7 +90,7 @@ impl Initializable for Initialization {
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum LogicalStatement {
     And(String, String),
     Or(String, String),
@@ -194,7 +205,7 @@ fn parse_wires(input: &str) -> IResult<&str, Vec<Initialization>> {
     Ok((input, wires))
 }
 
-fn parse_challenge(input: &str) -> IResult<&str, (Vec<Initialization>, VecDeque<Assignment>)> {
+pub fn parse_challenge(input: &str) -> IResult<&str, (Vec<Initialization>, VecDeque<Assignment>)> {
     let (input, wires) = parse_wires(input)?;
     let (input, _) = many1(line_ending)(input)?;
     let (input, assignments) = parse_assignments(input)?;
@@ -202,7 +213,29 @@ fn parse_challenge(input: &str) -> IResult<&str, (Vec<Initialization>, VecDeque<
     Ok((input, (wires, assignments)))
 }
 
-fn build_z_binary(lookup_table: &HashMap<String, u64>) -> u64 {
+/// Settle every gate, returning the value of each wire.
+pub fn evaluate(
+    wires: &[Initialization],
+    assignments: &VecDeque<Assignment>,
+) -> HashMap<String, u64> {
+    let mut lookup_table: HashMap<String, u64> = HashMap::new();
+    let mut assignments = assignments.clone();
+    wires.iter().for_each(|x| x.initialize(&mut lookup_table));
+    while !assignments.is_empty() {
+        if let Some(assignment) = assignments.pop_front() {
+            if assignment.calculable(&mut lookup_table) {
+                let value: u64 = assignment.0.calculate(&mut lookup_table);
+                lookup_table.insert(assignment.1, value);
+            } else {
+                assignments.push_back((assignment.0, assignment.1));
+            }
+        }
+    }
+    lookup_table
+}
+
+/// Read the `z` wires as the bits of a number, `z00` being the least significant.
+pub fn build_z_binary(lookup_table: &HashMap<String, u64>) -> u64 {
     let mut result: u64 = 0;
     for (key, value) in lookup_table.iter().filter(|(x, _)| x.starts_with("z")) {
         let tmp: u64 = key[1..].to_string().parse::<u64>().expect("not a number");
@@ -211,28 +244,57 @@ fn build_z_binary(lookup_table: &HashMap<String, u64>) -> u64 {
     result
 }
 
-impl CommandImpl for Day24 {
-    fn main(&self) -> Result<(), DynError> {
-        let blob_string = fs::read_to_string(&self.input)?;
-        let mut lookup_table: HashMap<String, u64> = HashMap::new();
-        if let Ok((_, (wires, mut assignments))) = parse_challenge(&blob_string) {
-            wires.into_iter().for_each(|x| x.initialize(&mut lookup_table));
-            while !assignments.is_empty() {
-                if let Some(assignment) = assignments.pop_front() {
-                    if assignment.calculable(&mut lookup_table) {
-                        let value: u64 = assignment.0.calculate(&mut lookup_table);
-                        lookup_table.insert(assignment.1, value);
-                    } else {
-                        assignments.push_back((assignment.0, assignment.1));
-                    }
-                }
+impl Solver for Day24 {
+    type Parsed = (Vec<Initialization>, VecDeque<Assignment>);
+
+    fn args(&self) -> &DayArgs {
+        &self.args
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, DynError> {
+        Ok(from_nom(input, parse_challenge(input))?)
+    }
+
+    fn part1(&self, (wires, assignments): &Self::Parsed) -> Result<Solution, DynError> {
+        let lookup_table = evaluate(wires, assignments);
+        let zbinary: u64 = build_z_binary(&lookup_table);
+        Ok(Solution::new().with_part1(zbinary))
+    }
+
+    fn generate(&self, rng: &mut InputRng, size: Option<usize>) -> Option<String> {
+        // `z` wires are read into a `u64`, so the sum has at most 64 bits.
+        let nbits = size.unwrap_or(45).clamp(1, 63);
+        let mut names: Vec<String> = Vec::new();
+        let mut name = |rng: &mut InputRng| loop {
+            let name: String = (0..3).map(|_| char::from(b'a' + rng.gen_range(0..23))).collect();
+            if !names.contains(&name) {
+                names.push(name.clone());
+                break name;
             }
-            let zbinary: u64 = build_z_binary(&lookup_table);
-            println!("zbinary = {zbinary}");
-        } else {
-            println!("unable to read {:?}", &self.input);
+        };
+        // A ripple-carry adder: each bit adds x and y and the carry from the bit below.
+        let mut gates: Vec<String> = vec!["x00 XOR y00 -> z00".to_string()];
+        let mut carry = name(rng);
+        gates.push(format!("x00 AND y00 -> {carry}"));
+        for bit in 1..nbits {
+            let (sum, both, carried) = (name(rng), name(rng), name(rng));
+            let next = if bit == nbits - 1 { format!("z{nbits:02}") } else { name(rng) };
+            gates.push(format!("x{bit:02} XOR y{bit:02} -> {sum}"));
+            gates.push(format!("x{bit:02} AND y{bit:02} -> {both}"));
+            gates.push(format!("{sum} XOR {carry} -> z{bit:02}"));
+            gates.push(format!("{sum} AND {carry} -> {carried}"));
+            gates.push(format!("{both} OR {carried} -> {next}"));
+            carry = next;
         }
-
-        Ok(())
+        if nbits == 1 {
+            gates[1] = "x00 AND y00 -> z01".to_string();
+        }
+        gates.shuffle(rng);
+        let wires: String = ["x", "y"]
+            .iter()
+            .flat_map(|wire| (0..nbits).map(move |bit| format!("{wire}{bit:02}")))
+            .map(|wire| format!("{wire}: {}\n", rng.gen_range(0..2)))
+            .collect();
+        Some(format!("{wires}\n{}\n", gates.join("\n")))
     }
 }
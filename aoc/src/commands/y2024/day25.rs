@@ -1,20 +1,30 @@
-use std::path::PathBuf;
-
 use clap::Parser;
 
-use nom::{character::complete::one_of, multi::many1};
+use nom::{character::complete::one_of, combinator::verify, multi::many1};
 
 use nom::character::complete::line_ending;
 use nom::multi::separated_list1;
 use nom::IResult;
-use std::fs;
+use rand::Rng;
 
-use super::{CommandImpl, DynError};
+use crate::commands::{DayArgs, DynError, Parts, Puzzle, Registration, Solution, Solver};
+use crate::utils::from_nom;
+use crate::utils::random::InputRng;
 
-#[derive(Parser, Debug)]
+#[derive(Parser, Debug, Default)]
 pub struct Day25 {
-    #[clap(long, short)]
-    input: PathBuf,
+    #[clap(flatten)]
+    args: DayArgs,
+}
+
+inventory::submit! {
+    Registration::new::<Day25>(Puzzle {
+        year: 2024,
+        day: 25,
+        title: "Code Chronicle",
+        parts: Parts::One,
+        input: "day25",
+    })
 }
 
 pub type Schematic = Vec<Vec<char>>;
@@ -132,47 +142,75 @@ fn parse_five_pins(input: &str) -> IResult<&str, Vec<char>> {
 }
 
 fn parse_lock(input: &str) -> IResult<&str, Schematic> {
-    let (input, locks) = separated_list1(line_ending, parse_five_pins)(input)?;
+    // Ragged rows cannot be transposed into pins.
+    let (input, locks) =
+        verify(separated_list1(line_ending, parse_five_pins), |rows: &[Vec<char>]| {
+            rows.iter().all(|row| row.len() == rows[0].len())
+        })(input)?;
     Ok((input, transpose(locks)))
 }
 
-fn parse_locks(input: &str) -> IResult<&str, Vec<Schematic>> {
+pub fn parse_locks(input: &str) -> IResult<&str, Vec<Schematic>> {
     let (input, locks) = separated_list1(many1(line_ending), parse_lock)(input)?;
     //let (input, locks) = newline(input)?;
     Ok((input, locks))
 }
 
-impl CommandImpl for Day25 {
-    fn main(&self) -> Result<(), DynError> {
-        let blob_string = fs::read_to_string(&self.input)?;
-        if let Ok((_, schematics)) = parse_locks(&blob_string) {
-            let key_lengths: Vec<Vec<usize>> = schematics
-                .iter()
-                .filter(|x| x.is_key())
-                .map(|x| x.key_heights().unwrap())
-                .collect();
-            let lock_lengths: Vec<Vec<usize>> = schematics
-                .iter()
-                .filter(|x| x.is_lock())
-                .map(|x| x.lock_heights().unwrap())
-                .collect();
-            let mut num_fits: usize = 0;
-            for key in key_lengths.iter() {
-                for lock in lock_lengths.iter() {
-                    if key.fits(lock) {
-                        num_fits += 1;
-                    }
+impl Solver for Day25 {
+    type Parsed = Vec<Schematic>;
+
+    fn args(&self) -> &DayArgs {
+        &self.args
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, DynError> {
+        Ok(from_nom(input, parse_locks(input))?)
+    }
+
+    fn part1(&self, schematics: &Self::Parsed) -> Result<Solution, DynError> {
+        let key_lengths: Vec<Vec<usize>> =
+            schematics.iter().filter(|x| x.is_key()).map(|x| x.key_heights().unwrap()).collect();
+        let lock_lengths: Vec<Vec<usize>> =
+            schematics.iter().filter(|x| x.is_lock()).map(|x| x.lock_heights().unwrap()).collect();
+        let mut num_fits: usize = 0;
+        for key in key_lengths.iter() {
+            for lock in lock_lengths.iter() {
+                if key.fits(lock) {
+                    num_fits += 1;
                 }
             }
-            println!("n fits: {:?}", num_fits);
-            //let locks: Vec<Lock> =
-            //schematics.iter().filter(|&x| x.is_lock()).collect::<Vec<Lock>>();
-            //println!("locks: {:?}", locks);
-        } else {
-            println!("unable to read {:?}", &self.input);
         }
+        //let locks: Vec<Lock> =
+        //schematics.iter().filter(|&x| x.is_lock()).collect::<Vec<Lock>>();
+        //println!("locks: {:?}", locks);
+        Ok(Solution::new().with_part1(num_fits))
+    }
 
-        Ok(())
+    fn generate(&self, rng: &mut InputRng, size: Option<usize>) -> Option<String> {
+        let schematics: Vec<String> = (0..size.unwrap_or(500).max(1))
+            .map(|_| {
+                let heights: Vec<usize> = (0..5).map(|_| rng.gen_range(0..=5)).collect();
+                // Locks hang pins from the top row; keys rise from the bottom one.
+                let lock = rng.gen_bool(0.5);
+                let rows: Vec<String> = (0..7)
+                    .map(|row| {
+                        heights
+                            .iter()
+                            .map(|&height| {
+                                let filled = if lock { row <= height } else { 6 - row <= height };
+                                if filled {
+                                    '#'
+                                } else {
+                                    '.'
+                                }
+                            })
+                            .collect()
+                    })
+                    .collect();
+                rows.join("\n")
+            })
+            .collect();
+        Some(schematics.join("\n\n") + "\n")
     }
 }
 
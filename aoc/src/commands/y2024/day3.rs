@@ -0,0 +1,106 @@
+use clap::Parser;
+use log::debug;
+
+use crate::utils::slurp_str;
+use rand::Rng;
+
+use crate::commands::{DayArgs, DynError, Parts, Puzzle, Registration, Solution, Solver};
+use crate::utils::random::InputRng;
+
+use regex::Regex;
+//use nom::sequence::preceded;
+
+#[derive(Parser, Debug, Default)]
+pub struct Day3 {
+    #[clap(flatten)]
+    args: DayArgs,
+}
+
+inventory::submit! {
+    Registration::new::<Day3>(Puzzle {
+        year: 2024,
+        day: 3,
+        title: "Mull It Over",
+        parts: Parts::Both,
+        input: "day3",
+    })
+}
+
+impl Solver for Day3 {
+    type Parsed = Vec<String>;
+
+    fn args(&self) -> &DayArgs {
+        &self.args
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, DynError> {
+        Ok(slurp_str(input)?)
+    }
+
+    fn part1(&self, string: &Self::Parsed) -> Result<Solution, DynError> {
+        let re_mult = Regex::new(r"mul\((?<lhs>[0-9]{1,3}),(?<rhs>[0-9]{1,3})\)")?;
+        let mut sum_product: u64 = 0;
+        for s in string.iter() {
+            for caps in re_mult.captures_iter(s) {
+                let lhs: u64 = caps["lhs"].parse::<u64>()?;
+                let rhs: u64 = caps["rhs"].parse::<u64>()?;
+                sum_product += lhs * rhs;
+            }
+        }
+
+        Ok(Solution::new().with_part1(sum_product))
+    }
+
+    fn part2(&self, string: &Self::Parsed) -> Result<Solution, DynError> {
+        debug!("length of string is {:?}", string.len());
+        //let re_set =
+        //    RegexSet::new(&[r"do\(\)", r"don't\(\)", r"mul\(([0-9]{1,3}),([0-9]{1,3})\)"]).unwrap();
+        let re = Regex::new(r"(mul\([0-9]{1,3},[0-9]{1,3}\)|do\(\)|don't\(\))")?;
+        let re_mult = Regex::new(r"mul\((?<lhs>[0-9]{1,3}),(?<rhs>[0-9]{1,3})\)")?;
+        let mut pushable: bool = true;
+        let mut products = vec![];
+        for s in string.iter() {
+            for (_, [msg]) in re.captures_iter(s).map(|c| c.extract()) {
+                //println!("{msg}");
+                if msg == "do()" {
+                    pushable = true;
+                } else if msg == "don't()" {
+                    pushable = false;
+                } else if pushable {
+                    let caps = re_mult.captures(msg).unwrap();
+                    let lhs: u64 = caps["lhs"].parse::<u64>()?;
+                    let rhs: u64 = caps["rhs"].parse::<u64>()?;
+                    products.push(lhs * rhs);
+                };
+            }
+        }
+        debug!("products: {:?}", products);
+        let sum_product: u64 = products.iter().sum();
+
+        Ok(Solution::new().with_part2(sum_product))
+    }
+
+    fn generate(&self, rng: &mut InputRng, size: Option<usize>) -> Option<String> {
+        const NOISE: &[u8] = b"!@#$%^&*()[]{}<>,;:'/?~-_+= whatfromselecthowwhywhenmul";
+        let ninstructions = size.unwrap_or(700).max(1);
+        let mut lines: Vec<String> = vec![String::new(); ninstructions.div_ceil(120)];
+        for i in 0..ninstructions {
+            let line = &mut lines[i / 120];
+            for _ in 0..rng.gen_range(0..12) {
+                line.push(NOISE[rng.gen_range(0..NOISE.len())] as char);
+            }
+            let (a, b) = (rng.gen_range(1..1000), rng.gen_range(1..1000));
+            let instruction = match rng.gen_range(0..10) {
+                0 => "do()".to_string(),
+                1 => "don't()".to_string(),
+                // Near misses the pattern has to skip.
+                2 => format!("mul({a},{b}]"),
+                3 => format!("mul ( {a} , {b} )"),
+                4 => format!("mul({a}*{b})"),
+                _ => format!("mul({a},{b})"),
+            };
+            line.push_str(&instruction);
+        }
+        Some(lines.join("\n") + "\n")
+    }
+}
@@ -1,15 +1,26 @@
-use std::path::PathBuf;
-
 use clap::Parser;
+use log::{debug, trace};
+use rand::Rng;
 
-use super::{CommandImpl, DynError};
-use crate::utils::slurp_file;
+use crate::commands::{DayArgs, DynError, Parts, Puzzle, Registration, Solution, Solver};
+use crate::utils::grid::Grid;
+use crate::utils::random::InputRng;
 use std::ops::{Add, Mul};
 
-#[derive(Parser, Debug)]
-pub struct Day4a {
-    #[clap(long, short)]
-    input: PathBuf,
+#[derive(Parser, Debug, Default)]
+pub struct Day4 {
+    #[clap(flatten)]
+    args: DayArgs,
+}
+
+inventory::submit! {
+    Registration::new::<Day4>(Puzzle {
+        year: 2024,
+        day: 4,
+        title: "Ceres Search",
+        parts: Parts::Both,
+        input: "day4",
+    })
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -93,17 +104,17 @@ impl Mul for Bits {
 //}
 
 pub struct Letters {
-    pub letters: Vec<Vec<Bits>>,
+    pub letters: Grid<Bits>,
 }
 
 impl Letters {
-    pub fn new(letters: Vec<Vec<Bits>>) -> Self {
+    pub fn new(letters: Grid<Bits>) -> Self {
         Self { letters }
     }
 
     pub fn display(&self) {
-        for i in 0..self.letters.len() {
-            println!("{:?}", self.letters[i]);
+        for row in self.letters.rows() {
+            debug!("{:?}", row);
         }
     }
 
@@ -126,8 +137,8 @@ impl Letters {
 
     pub fn cumsum(&self) -> usize {
         let mut n: usize = 0;
-        for i in 0..self.letters.len() {
-            for j in 0..self.letters[0].len() {
+        for i in 0..self.letters.nrows() {
+            for j in 0..self.letters.ncols() {
                 n += self.count_position(i, j);
             }
         }
@@ -136,8 +147,8 @@ impl Letters {
 
     pub fn cumsum_mas(&self) -> usize {
         let mut n: usize = 0;
-        for row in 1..self.letters.len() - 1 {
-            for col in 1..self.letters[0].len() - 1 {
+        for row in 1..self.letters.nrows().saturating_sub(1) {
+            for col in 1..self.letters.ncols().saturating_sub(1) {
                 if self.cross_match(row, col) {
                     n += 1;
                 }
@@ -147,73 +158,64 @@ impl Letters {
     }
 
     fn vertical(&self, row: usize, col: usize) -> bool {
-        if row > self.letters.len() - 4 {
+        if row + 4 > self.letters.nrows() {
             return false;
         }
-        if col > self.letters[0].len() {
+        if col > self.letters.ncols() {
             return false;
         }
         let mut x = Bits::Unknown;
         for i in row..(row + 4) {
-            x = x + self.letters[i][col];
+            x = x + self.letters[(i, col)];
         }
         x.matches()
     }
 
     fn cross_match(&self, row: usize, col: usize) -> bool {
-        let mut x = self.letters[row - 1][col - 1];
-        print!("{row},{col}: one - {:?}", x);
-        let z = self.letters[row][col];
-        x = x * z;
-        print!(", * {:?} = {:?}", z, x);
-        let z = self.letters[row + 1][col + 1];
-        x = x * z;
-        println!(", * {:?} = {:?}", z, x);
+        let mut x = self.letters[(row - 1, col - 1)];
+        x = x * self.letters[(row, col)];
+        x = x * self.letters[(row + 1, col + 1)];
 
-        let mut y = self.letters[row + 1][col - 1];
-        print!("{row},{col}: one - {:?}", y);
-        let z = self.letters[row][col];
-        y = y * z;
-        print!(", * {:?} = {:?}", z, y);
-        let z = self.letters[row - 1][col + 1];
-        y = y * z;
-        println!(", * {:?} = {:?}", z, y);
+        let mut y = self.letters[(row + 1, col - 1)];
+        y = y * self.letters[(row, col)];
+        y = y * self.letters[(row - 1, col + 1)];
+        trace!("{row},{col}: {:?}, {:?}", x, y);
         if x.matches_mas() && y.matches_mas() {
-            println!("matches {row},{col}");
+            debug!("matches {row},{col}");
         }
         x.matches_mas() && y.matches_mas()
     }
 
     //    fn vertical_mul(&self, row: usize, col: usize) -> bool {
-    //        if row > self.letters.len() - 3 {
+    //        if row > self.letters.nrows() - 3 {
     //            return false;
     //        }
-    //        if col > self.letters[0].len() {
+    //        if col > self.letters.ncols() {
     //            return false;
     //        }
     //        let mut x = Bits::Unknown;
     //        for i in row..(row + 3) {
-    //            x = x * self.letters[i][col];
+    //            x = x * self.letters[(i, col)];
     //        }
     //        x.matches_mas()
     //    }
     //
     fn horizontal(&self, row: usize, col: usize) -> bool {
-        if self.letters.is_empty() {
+        if self.letters.nrows() == 0 {
             return false;
         }
-        if col + 4 > self.letters[0].len() {
+        if col + 4 > self.letters.ncols() {
             return false;
         }
         let mut x = Bits::Unknown;
         for i in col..(col + 4) {
-            x = x + self.letters[row][i];
+            x = x + self.letters[(row, i)];
         }
         x.matches()
     }
 
     fn cross_diagonal(&self, row: usize, col: usize) -> bool {
-        if self.letters.is_empty() {
+        if self.letters.nrows() == 0 {
             return false;
         }
         if row < 3 {
@@ -224,16 +226,16 @@ impl Letters {
         }
         let mut x = Bits::Unknown;
         for i in 0..4 {
-            x = x + self.letters[row - i][col - i];
+            x = x + self.letters[(row - i, col - i)];
         }
         x.matches()
     }
 
     fn diagonal(&self, row: usize, col: usize) -> bool {
-        if self.letters.is_empty() {
+        if self.letters.nrows() == 0 {
             return false;
         }
-        if row + 4 > self.letters.len() {
+        if row + 4 > self.letters.nrows() {
             return false;
         }
         if col < 3 {
@@ -241,29 +243,74 @@ impl Letters {
         }
         let mut x = Bits::Unknown;
         for i in 0..4 {
-            x = x + self.letters[row + i][col - i];
+            x = x + self.letters[(row + i, col - i)];
         }
         x.matches()
     }
 }
 
-impl CommandImpl for Day4a {
-    fn main(&self) -> Result<(), DynError> {
-        let string: Vec<String> = slurp_file(&self.input)?;
-        let bits: Vec<Vec<Bits>> = string
-            .iter()
-            .map(|s| s.chars().collect::<Vec<char>>())
-            .map(|c| c.iter().map(|x| Bits::new(*x)).collect::<Vec<Bits>>())
-            .collect();
-        let letters: Letters = Letters::new(bits);
+impl Solver for Day4 {
+    type Parsed = Letters;
+
+    fn args(&self) -> &DayArgs {
+        &self.args
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, DynError> {
+        let bits = Grid::parse(input, |c| Some(Bits::new(c)))?;
+        Ok(Letters::new(bits))
+    }
+
+    fn part1(&self, letters: &Self::Parsed) -> Result<Solution, DynError> {
         //letters.display();
         let count: usize = letters.cumsum();
-
-        println!("counted XMAS {count} times");
-        let count_mas: usize = letters.cumsum_mas();
-        println!("counted cross-MAS {count_mas} times");
         //let n: usize = letters.count_position(4, 0);
         //println!("counted XMAS {n} times at (4,0)");
+        Ok(Solution::new().with_part1(count))
+    }
+
+    fn part2(&self, letters: &Self::Parsed) -> Result<Solution, DynError> {
+        let count_mas: usize = letters.cumsum_mas();
+        Ok(Solution::new().with_part2(count_mas))
+    }
+
+    fn generate(&self, rng: &mut InputRng, size: Option<usize>) -> Option<String> {
+        // Smaller than four letters a side, the grid has no room for XMAS.
+        let side = size.unwrap_or(140).max(4);
+        let mut letters: Grid<char> = Grid::filled(side, side, '.');
+        for p in letters.positions().collect::<Vec<_>>() {
+            letters[p] = ['X', 'M', 'A', 'S'][rng.gen_range(0..4)];
+        }
+        // Random letters rarely spell anything, so write some words in.
+        for _ in 0..side * side / 20 {
+            let (drow, dcol) =
+                [(0, 1), (1, 0), (1, 1), (1, -1), (0, -1), (-1, 0), (-1, -1), (-1, 1)]
+                    [rng.gen_range(0..8)];
+            let mut p = (rng.gen_range(0..side), rng.gen_range(0..side));
+            for c in "XMAS".chars() {
+                letters[p] = c;
+                match letters.offset(p, drow, dcol) {
+                    Some(next) => p = next,
+                    None => break,
+                }
+            }
+        }
+        Some(format!("{letters}\n"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_small_grids() -> Result<(), Box<dyn std::error::Error>> {
+        let day = Day4::default();
+        for input in ["X\n", "XM\nAS\n", "XMA\nMAS\nSAX\n"] {
+            let letters = day.parse(input)?;
+            assert_eq!(day.part1(&letters)?.part1, Some(0.into()), "{input:?}");
+            assert_eq!(day.part2(&letters)?.part2, Some(0.into()), "{input:?}");
+        }
         Ok(())
     }
 }
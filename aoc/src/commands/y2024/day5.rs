@@ -1,9 +1,5 @@
-use std::path::PathBuf;
-
 use clap::Parser;
 
-use std::fs;
-
 use std::collections::{HashMap, HashSet};
 
 use nom::bytes::complete::tag;
@@ -15,20 +11,32 @@ use nom::multi::many1;
 use nom::multi::separated_list1;
 use nom::sequence::separated_pair;
 use nom::IResult;
+use rand::seq::SliceRandom;
+use rand::Rng;
 
-use super::{CommandImpl, DynError};
+use crate::commands::{DayArgs, DynError, Parts, Puzzle, Registration, Solution, Solver};
+use crate::utils::from_nom;
+use crate::utils::random::InputRng;
 
 pub type Rule = (u8, u8);
-#[derive(Parser, Debug)]
-pub struct Day5a {
-    #[clap(long, short)]
-    input: PathBuf,
+#[derive(Parser, Debug, Default)]
+pub struct Day5 {
+    #[clap(flatten)]
+    args: DayArgs,
+}
+
+inventory::submit! {
+    Registration::new::<Day5>(Puzzle {
+        year: 2024,
+        day: 5,
+        title: "Print Queue",
+        parts: Parts::Both,
+        input: "day5",
+    })
 }
 
 fn parse_rule(input: &str) -> IResult<&str, Rule> {
-    let (input, p) = separated_pair(digit1, tag("|"), digit1)(input)?;
-    let integers = (p.0.to_string().parse::<u8>().unwrap(), p.1.to_string().parse::<u8>().unwrap());
-    Ok((input, integers))
+    separated_pair(my_u8, tag("|"), my_u8)(input)
 }
 
 fn parse_rules(input: &str) -> IResult<&str, HashMap<u8, HashSet<u8>>> {
@@ -59,7 +67,9 @@ fn parse_updates(input: &str) -> IResult<&str, Vec<Vec<u8>>> {
     Ok((input, updates))
 }
 
-fn parse_problem(input: &str) -> IResult<&str, (HashMap<u8, HashSet<u8>>, Vec<Vec<u8>>)> {
+pub type RuleMap = HashMap<u8, HashSet<u8>>;
+
+pub fn parse_problem(input: &str) -> IResult<&str, (RuleMap, Vec<Vec<u8>>)> {
     let (input, rules) = parse_rules(input)?;
     let (input, _) = many1(newline)(input)?;
     let (input, updates) = parse_updates(input)?;
@@ -75,7 +85,7 @@ impl SafetyManual {
         Self { rules }
     }
 
-    fn is_update_ordered(&self, updates: &Vec<u8>) -> bool {
+    fn is_update_ordered(&self, updates: &[u8]) -> bool {
         let mut printed = HashSet::new();
         for page in updates {
             if let Some(descendent) = self.rules.get(page) {
@@ -134,19 +144,55 @@ impl SafetyManual {
     }
 }
 
-impl CommandImpl for Day5a {
-    fn main(&self) -> Result<(), DynError> {
-        let file = fs::read_to_string(&self.input)?;
-        if let Ok((_, (rules, mut updates))) = parse_problem(&file) {
-            let safety_manual = SafetyManual::new(rules);
-            let mut updates = safety_manual.filter_ordered(&mut updates);
-            updates.iter_mut().for_each(|x| safety_manual.pair_sort(x));
-            let sum_middle_values = safety_manual.middle_value_sum(&updates);
-            println!("sum of middle values: {sum_middle_values}");
-        } else {
-            println!("error");
+impl Solver for Day5 {
+    type Parsed = (RuleMap, Vec<Vec<u8>>);
+
+    fn args(&self) -> &DayArgs {
+        &self.args
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, DynError> {
+        Ok(from_nom(input, parse_problem(input))?)
+    }
+
+    fn part1(&self, (rules, updates): &Self::Parsed) -> Result<Solution, DynError> {
+        let safety_manual = SafetyManual::new(rules.clone());
+        let updates = safety_manual.filter_unordered(&mut updates.clone());
+        Ok(Solution::new().with_part1(safety_manual.middle_value_sum(&updates)))
+    }
+
+    fn part2(&self, (rules, updates): &Self::Parsed) -> Result<Solution, DynError> {
+        let safety_manual = SafetyManual::new(rules.clone());
+        let mut updates = safety_manual.filter_ordered(&mut updates.clone());
+        updates.iter_mut().for_each(|x| safety_manual.pair_sort(x));
+        let sum_middle_values = safety_manual.middle_value_sum(&updates);
+        Ok(Solution::new().with_part2(sum_middle_values))
+    }
+
+    fn generate(&self, rng: &mut InputRng, size: Option<usize>) -> Option<String> {
+        // One hidden order of the pages, with a rule for every pair of them.
+        let mut pages: Vec<u8> = (10..100).collect();
+        pages.shuffle(rng);
+        pages.truncate(49);
+        let mut rules: Vec<String> = Vec::new();
+        for (i, before) in pages.iter().enumerate() {
+            for after in pages[i + 1..].iter() {
+                rules.push(format!("{before}|{after}"));
+            }
         }
-        Ok(())
+        rules.shuffle(rng);
+        let mut updates: Vec<String> = Vec::new();
+        for _ in 0..size.unwrap_or(200) {
+            let len = 2 * rng.gen_range(2..=11) + 1;
+            let mut update: Vec<u8> = pages.choose_multiple(rng, len).copied().collect();
+            if rng.gen_bool(0.5) {
+                let rank = |page: &u8| pages.iter().position(|p| p == page);
+                update.sort_by_key(rank);
+            }
+            let update: Vec<String> = update.iter().map(u8::to_string).collect();
+            updates.push(update.join(","));
+        }
+        Some(format!("{}\n\n{}\n", rules.join("\n"), updates.join("\n")))
     }
 }
 
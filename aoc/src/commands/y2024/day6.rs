@@ -0,0 +1,143 @@
+use clap::Parser;
+
+use crate::utils::geometry::{Direction, Heading};
+use crate::utils::grid::Grid;
+use rand::Rng;
+
+use crate::commands::{DayArgs, DynError, Parts, Puzzle, Registration, Solution, Solver};
+use crate::utils::random::InputRng;
+
+use std::collections::HashSet;
+
+#[derive(Parser, Debug, Default)]
+pub struct Day6 {
+    #[clap(flatten)]
+    args: DayArgs,
+}
+
+inventory::submit! {
+    Registration::new::<Day6>(Puzzle {
+        year: 2024,
+        day: 6,
+        title: "Guard Gallivant",
+        parts: Parts::Both,
+        input: "day6",
+    })
+}
+
+pub fn differences(vec: &[i32]) -> Vec<i32> {
+    vec.windows(2).map(|w| w[1] - w[0]).collect()
+}
+
+pub fn starting_position(mapped_area: &Grid<char>) -> Option<Heading> {
+    mapped_area.find(&'^').map(|p| Heading::new(p, Direction::North))
+}
+
+pub fn obstacles(mapped_area: &Grid<char>) -> HashSet<(usize, usize)> {
+    mapped_area.find_all(&'#').collect()
+}
+
+pub fn simulate(
+    start: Heading,
+    obstacles: &HashSet<(usize, usize)>,
+    dimensions: (usize, usize),
+) -> HashSet<(usize, usize)> {
+    let mut visited: HashSet<(usize, usize)> = HashSet::new();
+    let mut guard = start;
+
+    visited.insert(guard.position);
+    while let Some(next_p) = guard.step(dimensions) {
+        if !obstacles.contains(&next_p.position) {
+            guard = next_p;
+            visited.insert(guard.position);
+        } else {
+            guard = guard.turn_right();
+        }
+    }
+    visited
+}
+
+pub fn stuck_in_a_loop(
+    start: Heading,
+    obstacles: &HashSet<(usize, usize)>,
+    dimensions: (usize, usize),
+) -> bool {
+    let mut visited: HashSet<Heading> = HashSet::new();
+    let mut guard: Heading = start;
+
+    visited.insert(guard);
+    while let Some(next_p) = guard.step(dimensions) {
+        if visited.contains(&next_p) {
+            return true;
+        } else if !obstacles.contains(&next_p.position) {
+            guard = next_p;
+            visited.insert(guard);
+        } else {
+            guard = guard.turn_right();
+        }
+    }
+    false
+}
+
+impl Solver for Day6 {
+    type Parsed = Grid<char>;
+
+    fn args(&self) -> &DayArgs {
+        &self.args
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, DynError> {
+        Ok(Grid::parse(input, |c| matches!(c, '.' | '#' | '^').then_some(c))?)
+    }
+
+    fn part1(&self, mapped_area: &Self::Parsed) -> Result<Solution, DynError> {
+        let obstacles = obstacles(mapped_area);
+        let dimensions = mapped_area.dimensions();
+        let mut solution = Solution::new();
+        if let Some(guard) = starting_position(mapped_area) {
+            let visited = simulate(guard, &obstacles, dimensions);
+            solution = solution.with_part1(visited.len());
+        }
+
+        Ok(solution)
+    }
+
+    fn part2(&self, mapped_area: &Self::Parsed) -> Result<Solution, DynError> {
+        let mut obstacles = obstacles(mapped_area);
+        let dimensions = mapped_area.dimensions();
+        let mut nloops: usize = 0usize;
+        let mut solution = Solution::new();
+        if let Some(guard) = starting_position(mapped_area) {
+            let visited = simulate(guard, &obstacles, dimensions);
+            for p in visited.iter() {
+                obstacles.insert(*p);
+                if stuck_in_a_loop(guard, &obstacles, dimensions) {
+                    nloops += 1;
+                }
+                obstacles.remove(p);
+            }
+            solution = solution.with_part2(nloops);
+        }
+
+        Ok(solution)
+    }
+
+    fn generate(&self, rng: &mut InputRng, size: Option<usize>) -> Option<String> {
+        let side = size.unwrap_or(130).max(2);
+        // Part one follows the guard until they leave, so only keep maps they can walk out of.
+        loop {
+            let mut area: Grid<char> = Grid::filled(side, side, '.');
+            for p in area.positions().collect::<Vec<_>>() {
+                if rng.gen_bool(0.05) {
+                    area[p] = '#';
+                }
+            }
+            let guard = (rng.gen_range(0..side), rng.gen_range(0..side));
+            area[guard] = '^';
+            let start = Heading::new(guard, Direction::North);
+            if !stuck_in_a_loop(start, &obstacles(&area), area.dimensions()) {
+                return Some(format!("{area}\n"));
+            }
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use clap::Parser;
+use rand::Rng;
+
+use crate::commands::{DayArgs, DynError, Parts, Puzzle, Registration, Solution, Solver};
+use crate::utils::from_nom;
+use crate::utils::random::InputRng;
+use std::collections::VecDeque;
+
+use nom::bytes::complete::tag;
+use nom::character::complete::digit1;
+use nom::character::complete::line_ending;
+use nom::character::complete::space0;
+use nom::character::complete::space1;
+use nom::combinator::map_res;
+use nom::multi::separated_list1;
+use nom::IResult;
+//use nom::sequence::preceded;
+
+#[derive(Parser, Debug, Default)]
+pub struct Day7 {
+    #[clap(flatten)]
+    args: DayArgs,
+}
+
+inventory::submit! {
+    Registration::new::<Day7>(Puzzle {
+        year: 2024,
+        day: 7,
+        title: "Bridge Repair",
+        parts: Parts::Both,
+        input: "day7",
+    })
+}
+
+fn my_digit(input: &str) -> IResult<&str, u64> {
+    map_res(digit1, str::parse)(input)
+}
+
+fn parse_test(input: &str) -> IResult<&str, (u64, Vec<u64>)> {
+    let (input, test_value) = my_digit(input)?;
+    let (input, _) = tag(":")(input)?;
+    let (input, _) = space0(input)?;
+    let (input, rhs) = separated_list1(space1, my_digit)(input)?;
+    Ok((input, (test_value, rhs)))
+}
+
+fn concat(lhs: u64, rhs: u64) -> Result<u64, DynError> {
+    let l: String = lhs.to_string();
+    let r: String = rhs.to_string();
+    let lr: String = format!("{l}{r}");
+    Ok(lr.parse::<u64>()?)
+}
+
+pub fn parse_tests(input: &str) -> IResult<&str, Vec<(u64, Vec<u64>)>> {
+    let (input, rhs) = separated_list1(line_ending, parse_test)(input)?;
+    Ok((input, rhs))
+}
+
+/// Whether some combination of `+`, `*` and, when `concatenation` is set, `||` makes `test_value`.
+fn solvable(test_value: u64, operands: &[u64], concatenation: bool) -> bool {
+    let base: usize = 2;
+    let capacity = operands.len() as u32;
+    let mut solution_tree: VecDeque<(usize, u64)> = VecDeque::with_capacity(base.pow(capacity));
+    solution_tree.push_back((0, operands[0]));
+
+    while let Some(top) = solution_tree.pop_front() {
+        if top.0 == operands.len() - 1 {
+            if top.1 == test_value {
+                return true;
+            }
+            continue;
+        }
+        let lhs: u64 = top.1 + operands[top.0 + 1];
+        let rhs: u64 = top.1 * operands[top.0 + 1];
+        if lhs <= test_value {
+            solution_tree.push_back((top.0 + 1, lhs));
+        }
+        if rhs <= test_value {
+            solution_tree.push_back((top.0 + 1, rhs));
+        }
+        if concatenation {
+            let mid: u64 = concat(top.1, operands[top.0 + 1]).unwrap();
+            if mid <= test_value {
+                solution_tree.push_back((top.0 + 1, mid));
+            }
+        }
+    }
+    false
+}
+
+impl Solver for Day7 {
+    type Parsed = Vec<(u64, Vec<u64>)>;
+
+    fn args(&self) -> &DayArgs {
+        &self.args
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, DynError> {
+        Ok(from_nom(input, parse_tests(input))?)
+    }
+
+    fn part1(&self, tests: &Self::Parsed) -> Result<Solution, DynError> {
+        let sum: u64 = tests.iter().filter(|x| solvable(x.0, &x.1, false)).map(|x| x.0).sum();
+        Ok(Solution::new().with_part1(sum))
+    }
+
+    fn part2(&self, tests: &Self::Parsed) -> Result<Solution, DynError> {
+        let sum: u64 = tests.iter().filter(|x| solvable(x.0, &x.1, true)).map(|x| x.0).sum();
+        Ok(Solution::new().with_part2(sum))
+    }
+
+    fn generate(&self, rng: &mut InputRng, size: Option<usize>) -> Option<String> {
+        // Keeps every partial result the solver tries, even concatenated, inside a `u64`.
+        const LIMIT: u64 = 10_000_000_000_000;
+        let mut input = String::new();
+        for _ in 0..size.unwrap_or(850) {
+            let operands: Vec<u64> =
+                (0..rng.gen_range(2..=12)).map(|_| rng.gen_range(1..1000)).collect();
+            let mut value: u64 = operands[0];
+            for &operand in operands[1..].iter() {
+                let next = match rng.gen_range(0..3) {
+                    0 => value * operand,
+                    1 => concat(value, operand).unwrap_or(u64::MAX),
+                    _ => value + operand,
+                };
+                value = if next <= LIMIT { next } else { value + operand };
+            }
+            // Nudge about half of the test values so that they probably cannot be made.
+            if rng.gen_bool(0.5) {
+                value += rng.gen_range(1..10);
+            }
+            let operands: Vec<String> = operands.iter().map(u64::to_string).collect();
+            input.push_str(&format!("{value}: {}\n", operands.join(" ")));
+        }
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case("190: 10 19", 2usize)]
+    fn test_parse_test(
+        #[case] input: &'static str,
+        #[case] expected: usize,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let (_, (x, v)) = parse_test(input)?;
+        assert_eq!(x, 190u64);
+        assert_eq!(v.len(), expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("190: 10 19", true, true)]
+    #[case("156: 15 6", false, true)]
+    #[case("83: 17 5", false, false)]
+    fn test_solvable(
+        #[case] input: &'static str,
+        #[case] without_concatenation: bool,
+        #[case] with_concatenation: bool,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let (_, (x, v)) = parse_test(input)?;
+        assert_eq!(solvable(x, &v, false), without_concatenation);
+        assert_eq!(solvable(x, &v, true), with_concatenation);
+        Ok(())
+    }
+}
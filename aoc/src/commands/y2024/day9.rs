@@ -0,0 +1,334 @@
+use clap::Parser;
+use log::debug;
+
+use nom::lib::std::cmp::Ordering;
+use rand::Rng;
+use std::cmp::min;
+use std::collections::VecDeque;
+use std::ops::Range;
+
+use crate::commands::{DayArgs, DynError, Parts, Puzzle, Registration, Solution, Solver};
+use crate::utils::random::InputRng;
+use crate::utils::AocError;
+
+//use nom::sequence::preceded;
+
+#[derive(Parser, Debug, Default)]
+pub struct Day9 {
+    #[clap(flatten)]
+    args: DayArgs,
+}
+
+inventory::submit! {
+    Registration::new::<Day9>(Puzzle {
+        year: 2024,
+        day: 9,
+        title: "Disk Fragmenter",
+        parts: Parts::Both,
+        input: "day9",
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct Space {
+    id: Option<usize>,
+    range: Range<usize>,
+}
+
+impl PartialEq for Space {
+    fn eq(&self, other: &Self) -> bool {
+        self.range.start == other.range.start
+    }
+}
+
+impl PartialOrd for Space {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.range.start.partial_cmp(&other.range.start)
+    }
+}
+
+impl Space {
+    pub fn new(id: Option<usize>, start: usize, nblocks: usize) -> Self {
+        Self { id, range: Range { start, end: (start + nblocks) } }
+    }
+
+    pub fn free_block(&self) -> bool {
+        self.id.is_none()
+    }
+
+    pub fn nblocks(&self) -> usize {
+        self.range.end - self.range.start
+    }
+
+    pub fn contains(&self, other: &Self) -> bool {
+        self.nblocks() >= other.nblocks()
+    }
+
+    pub fn value(&self) -> Option<usize> {
+        if let Some(id) = self.id {
+            let mut v: usize = 0;
+            for i in self.range.start..self.range.end {
+                v += i * id;
+            }
+            Some(v)
+        } else {
+            None
+        }
+    }
+
+    pub fn fill(&self, other: &Self) -> Self {
+        Space::new(other.id, self.range.start, min(self.nblocks(), other.nblocks()))
+    }
+
+    pub fn remainder(&self, other: &Self) -> Option<Self> {
+        if self.nblocks() > other.nblocks() {
+            Some(Space::new(
+                self.id,
+                self.range.start + other.nblocks(),
+                self.nblocks() - other.nblocks(),
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct FileBlocks {
+    free_blocks: Vec<Space>,
+    file_blocks: Vec<Space>,
+}
+
+impl FileBlocks {
+    pub fn new(mut free_blocks: Vec<Space>, mut file_blocks: Vec<Space>) -> Self {
+        file_blocks.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        free_blocks.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        Self { free_blocks, file_blocks }
+    }
+
+    pub fn free_space(&self) -> bool {
+        self.free_blocks.is_empty()
+    }
+
+    pub fn compress(&mut self) {
+        let mut stack: Vec<Space> = Vec::new();
+        while let Some(top) = self.file_blocks.pop() {
+            let mut pushed: bool = false;
+            for free_block_index in 0..self.free_blocks.len() {
+                if self.free_blocks[free_block_index].contains(&top) {
+                    let free_block = self.free_blocks.remove(free_block_index);
+                    stack.push(free_block.fill(&top));
+                    if let Some(fblock) = free_block.remainder(&top) {
+                        self.free_blocks.push(fblock);
+                    }
+                    self.free_blocks.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                    pushed = true;
+                    break;
+                }
+            }
+            if !pushed {
+                stack.push(top);
+            }
+        }
+        self.file_blocks = stack
+    }
+}
+
+pub trait Identifiable {
+    fn id(&self) -> usize;
+}
+
+pub trait Blockable {
+    fn start(&self) -> usize;
+    fn end(&self) -> usize;
+    fn len(&self) -> usize;
+    fn is_empty(&self) -> bool {
+        self.len() == 0usize
+    }
+}
+
+pub trait Subtractable {
+    fn sub(&self, other: &Self) -> Self;
+}
+
+pub trait AbsoluteDifferencable {
+    fn abs_diff(&self, other: &Self) -> usize;
+}
+
+pub trait Relocatable {
+    fn relocate(&self, start: usize) -> Self;
+}
+
+pub trait CheckSummable {
+    fn checksum(&self) -> usize;
+}
+
+pub type Block = (usize, usize, usize);
+
+impl CheckSummable for Block {
+    fn checksum(&self) -> usize {
+        (self.start()..self.end()).map(|i| i * self.id()).sum()
+    }
+}
+
+impl Relocatable for Block {
+    fn relocate(&self, start: usize) -> Self {
+        (start, start + self.len(), self.id())
+    }
+}
+
+impl AbsoluteDifferencable for Block {
+    fn abs_diff(&self, other: &Self) -> usize {
+        other.start() - self.end()
+    }
+}
+
+impl Subtractable for Block {
+    fn sub(&self, other: &Self) -> Block {
+        (self.end(), other.start(), usize::MAX)
+    }
+}
+
+impl Blockable for Block {
+    fn start(&self) -> usize {
+        self.0
+    }
+
+    fn end(&self) -> usize {
+        self.1
+    }
+
+    fn len(&self) -> usize {
+        self.1 - self.0
+    }
+}
+
+impl Identifiable for Block {
+    fn id(&self) -> usize {
+        self.2
+    }
+}
+
+/// Move whole files, highest id first, into the leftmost gap that fits them.
+pub fn compact(disk_map: &[usize]) -> VecDeque<Block> {
+    let mut block_offset: usize = 0;
+    let mut file_blocks: Vec<Block> = disk_map
+        .iter()
+        .enumerate()
+        .filter_map(|(i, v)| {
+            let block: Option<Block> =
+                if i % 2 == 0 { Some((block_offset, block_offset + *v, i / 2)) } else { None };
+            block_offset += *v;
+            block
+        })
+        .collect::<Vec<Block>>();
+
+    let mut final_queue: VecDeque<Block> = VecDeque::new();
+    debug!("file blocks = {:?}", file_blocks);
+    while let Some(top) = file_blocks.pop() {
+        let mut swapped: bool = false;
+        for i in 1..file_blocks.len() {
+            //let abs_diff: usize = file_blocks[i - 1].abs_diff(&file_blocks[i]);
+            if file_blocks[i - 1].abs_diff(&file_blocks[i]) >= top.len() {
+                file_blocks.insert(i, top.relocate(file_blocks[i - 1].end()));
+                swapped = true;
+                break;
+            }
+        }
+        if file_blocks.is_empty() {
+            final_queue.push_front(top);
+        } else if !swapped {
+            let last = file_blocks.len() - 1;
+            // corner case: space before last
+            if file_blocks[last].abs_diff(&top) >= top.len() {
+                final_queue.push_front(top.relocate(file_blocks[last].end()));
+            } else {
+                final_queue.push_front(top);
+            }
+        }
+    }
+    final_queue
+}
+
+impl Solver for Day9 {
+    type Parsed = Vec<usize>;
+
+    fn args(&self) -> &DayArgs {
+        &self.args
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, DynError> {
+        input
+            .char_indices()
+            .filter(|(_, c)| *c != '\n')
+            .map(|(i, c)| match c.to_digit(10) {
+                Some(digit) => Ok(digit as usize),
+                None => Err(AocError::at(input, &input[i..], "digit").into()),
+            })
+            .collect()
+    }
+
+    fn part1(&self, disk_map: &Self::Parsed) -> Result<Solution, DynError> {
+        let mut block_offset: usize = 0;
+        let file_blocks = disk_map
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                let id: Option<usize> = if i % 2 == 0 { Some(i / 2) } else { None };
+                let space: Space = Space::new(id, block_offset, *v);
+                block_offset += *v;
+                space
+            })
+            .collect::<Vec<Space>>();
+        debug!("file blocks {:?}", file_blocks);
+
+        let file_size: usize = disk_map.iter().sum();
+        let mut block: Vec<usize> = vec![usize::MAX; file_size + 1];
+        let mut block_index: usize = 0;
+        for (i, v) in disk_map.iter().enumerate() {
+            let block_id: usize = if i % 2 == 0 { i / 2 } else { usize::MAX };
+            for j in 0..(*v) {
+                block[block_index + j] = block_id;
+            }
+            block_index += *v;
+        }
+        for front in 0..block.len() {
+            if block[front] == usize::MAX {
+                for back in (front..block.len()).rev() {
+                    if block[back] != usize::MAX {
+                        block.swap(front, back);
+                        break;
+                    }
+                }
+            }
+        }
+        let mut mysum: usize = 0;
+        let index = block.iter().position(|&x| x == usize::MAX).unwrap();
+        for (i, v) in block[..index].iter().enumerate() {
+            mysum += i * v;
+        }
+
+        Ok(Solution::new().with_part1(mysum))
+    }
+
+    fn part2(&self, disk_map: &Self::Parsed) -> Result<Solution, DynError> {
+        let final_queue = compact(disk_map);
+        let checksum: usize = final_queue.iter().map(|x| x.checksum()).sum();
+        debug!("final_queue = {:?}", final_queue);
+        //6286182965311
+
+        Ok(Solution::new().with_part2(checksum))
+    }
+
+    fn generate(&self, rng: &mut InputRng, size: Option<usize>) -> Option<String> {
+        let len = size.unwrap_or(19999).max(1);
+        // Even positions are files, which are never empty; odd ones are the gaps between them.
+        let disk_map: String = (0..len)
+            .map(|i| {
+                let digit = if i % 2 == 0 { rng.gen_range(1..10) } else { rng.gen_range(0..10) };
+                char::from(b'0' + digit)
+            })
+            .collect();
+        Some(disk_map + "\n")
+    }
+}
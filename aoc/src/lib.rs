@@ -0,0 +1,21 @@
+//! Advent of Code solutions as a library.
+//!
+//! Every day lives in [`commands`], under a module for its year, as a struct implementing
+//! [`commands::Solver`] next to the domain types and helpers it is built from. [`commands::Day`]
+//! looks days up by year and day, so a caller can solve any of them without going through the
+//! command line:
+//!
+//! ```no_run
+//! use aoc::commands::{CommandImpl, Day, PuzzleId};
+//!
+//! let input = std::fs::read_to_string("aoc/inputs/2024/day13.txt")?;
+//! let run = Day::from_id(PuzzleId::new(2024, 13))?.run(&input)?;
+//! println!("{}", run.solution);
+//! # Ok::<(), aoc::commands::DynError>(())
+//! ```
+pub mod commands;
+pub mod logging;
+pub mod memory;
+pub mod output;
+pub mod tasks;
+pub mod utils;
@@ -0,0 +1,81 @@
+use std::fs::File;
+use std::path::PathBuf;
+
+use clap::{ArgAction, Args};
+use env_logger::{Builder, Target, WriteStyle};
+use log::LevelFilter;
+
+use crate::commands::DynError;
+use crate::utils::AocError;
+
+/// Command line options controlling how much is logged, and where to.
+#[derive(Args, Debug, Clone, Default)]
+pub struct Logging {
+    /// Log more: once for info, twice for debug, three times for trace
+    #[clap(long, short, global = true, action = ArgAction::Count)]
+    verbose: u8,
+    /// Log less: once for errors only, twice for nothing at all
+    #[clap(long, short, global = true, action = ArgAction::Count, conflicts_with = "verbose")]
+    quiet: u8,
+    /// Write log records to this file instead of stderr
+    #[clap(long, global = true)]
+    log_file: Option<PathBuf>,
+}
+
+impl Logging {
+    /// The most detailed level that gets logged; warnings and errors by default.
+    pub fn level(&self) -> LevelFilter {
+        match (self.verbose, self.quiet) {
+            (0, 0) => LevelFilter::Warn,
+            (0, 1) => LevelFilter::Error,
+            (0, _) => LevelFilter::Off,
+            (1, _) => LevelFilter::Info,
+            (2, _) => LevelFilter::Debug,
+            _ => LevelFilter::Trace,
+        }
+    }
+
+    /// Install the global logger. `RUST_LOG` still takes precedence over the flags, so that
+    /// individual modules can be singled out.
+    pub fn init(&self) -> Result<(), DynError> {
+        let mut builder = Builder::new();
+        builder.filter_level(self.level()).parse_default_env();
+        if let Some(path) = &self.log_file {
+            let file =
+                File::create(path).map_err(|source| AocError::Io { path: path.clone(), source })?;
+            builder.target(Target::Pipe(Box::new(file))).write_style(WriteStyle::Never);
+        }
+        builder.try_init()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use clap::Parser;
+    use rstest::*;
+
+    #[derive(Parser, Debug)]
+    struct Opts {
+        #[clap(flatten)]
+        logging: Logging,
+    }
+
+    #[rstest]
+    #[case(&[], LevelFilter::Warn)]
+    #[case(&["-v"], LevelFilter::Info)]
+    #[case(&["-vv"], LevelFilter::Debug)]
+    #[case(&["-v", "-v", "-v", "-v"], LevelFilter::Trace)]
+    #[case(&["-q"], LevelFilter::Error)]
+    #[case(&["--quiet", "--quiet"], LevelFilter::Off)]
+    fn test_level(#[case] args: &[&str], #[case] expected: LevelFilter) {
+        let opts = Opts::parse_from(std::iter::once("aoc").chain(args.iter().copied()));
+        assert_eq!(opts.logging.level(), expected);
+    }
+
+    #[test]
+    fn test_verbose_conflicts_with_quiet() {
+        assert!(Opts::try_parse_from(["aoc", "-v", "-q"]).is_err());
+    }
+}
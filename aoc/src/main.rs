@@ -1,51 +1,79 @@
-pub mod commands;
-pub mod utils;
+use aoc::commands::{Day, DynError};
+use aoc::logging::Logging;
+use aoc::memory::{self, CountingAllocator};
+use aoc::output::{print_json, DayReport, Format};
+use aoc::tasks::{Task, TaskImpl};
 
-use commands::*;
-use enum_dispatch::enum_dispatch;
+use std::process;
 
-use clap::Parser;
+use log::info;
 
+use clap::{Parser, Subcommand};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Solve Advent of Code puzzles, and generate, check and keep track of their inputs and answers
 #[derive(Parser, Debug)]
 struct Opts {
+    /// How to write results
+    #[clap(long, global = true, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// Count the allocations each phase of a day makes, and report them with its results
+    #[clap(long, global = true)]
+    mem_stats: bool,
+    #[clap(flatten)]
+    logging: Logging,
     #[clap(subcommand)]
     subcommand: SubCommand,
 }
 
-#[enum_dispatch(CommandImpl)]
-#[derive(Parser, Debug)]
+#[derive(Subcommand, Debug)]
 enum SubCommand {
-    Day1a(day1a::Day1a),
-    Day1b(day1b::Day1b),
-    Day2a(day2a::Day2a),
-    Day3a(day3a::Day3a),
-    Day4a(day4a::Day4a),
-    Day4b(day4b::Day4b),
-    Day5a(day5a::Day5a),
-    Day5b(day5b::Day5b),
-    Day6a(day6a::Day6a),
-    Day7a(day7a::Day7a),
-    Day9a(day9a::Day9a),
-    Day9b(day9b::Day9b),
-    Day10(day10::Day10),
-    Day11(day11::Day11),
-    Day12(day12::Day12),
-    Day13(day13::Day13),
-    Day14(day14::Day14),
-    Day15(day15::Day15),
-    Day16(day16::Day16),
-    Day17(day17::Day17),
-    Day18(day18::Day18),
-    Day19(day19::Day19),
-    Day20(day20::Day20),
-    Day21(day21::Day21),
-    Day22(day22::Day22),
-    Day23(day23::Day23),
-    Day24(day24::Day24),
-    Day25(day25::Day25),
+    #[clap(flatten)]
+    Day(Day),
+    #[clap(flatten)]
+    Task(Task),
 }
-fn main() -> Result<(), DynError> {
+
+fn main() {
     let opts = Opts::parse();
+    if opts.mem_stats {
+        memory::enable();
+    }
+
+    if let Err(error) = opts.logging.init().and_then(|_| run(opts)) {
+        eprintln!("error: {error}");
+        process::exit(1);
+    }
+}
 
-    opts.subcommand.main()
+fn run(opts: Opts) -> Result<(), DynError> {
+    match opts.subcommand {
+        SubCommand::Day(day) => {
+            let run = day.main()?;
+            match opts.format {
+                Format::Text => {
+                    for diagnostic in run.solution.diagnostics.iter() {
+                        info!("{diagnostic}");
+                    }
+                    println!("{}", run.solution);
+                    if opts.mem_stats {
+                        println!("parse: {}", run.memory.parse);
+                        println!("solve: {}", run.memory.solve);
+                    }
+                }
+                Format::Json => {
+                    let report = DayReport::new(day.id(), &run);
+                    if opts.mem_stats {
+                        print_json(&report.with_memory(&run))?
+                    } else {
+                        print_json(&report)?
+                    }
+                }
+            }
+        }
+        SubCommand::Task(task) => task.main(opts.format)?,
+    }
+    Ok(())
 }
@@ -0,0 +1,265 @@
+//! Counting of heap allocations, behind `--mem-stats`.
+//!
+//! The counts are kept per thread, so days running side by side in `run-all` each see only their
+//! own allocations. Memory a day allocates on a thread of its own is not counted.
+//!
+//! On Linux each phase also reports the peak resident memory of the process, read from
+//! `/proc/self/status`. That figure is for the whole process, so with days running side by side
+//! it takes in their memory too.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt;
+use std::fs;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde::Serialize;
+
+/// A global allocator that hands every request to the system allocator and, once [`enable`] has
+/// been called, counts what each thread allocates.
+///
+/// A binary opts in with `#[global_allocator] static ALLOCATOR: CountingAllocator =
+/// CountingAllocator;`. Without it, everything [`measure`] reports is zero.
+pub struct CountingAllocator;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Running totals for one thread.
+#[derive(Debug, Clone, Copy)]
+struct Counter {
+    allocations: u64,
+    allocated: u64,
+    /// Bytes allocated and not yet freed. Memory freed on another thread than the one that
+    /// allocated it can take this below zero.
+    live: i64,
+    peak: i64,
+}
+
+thread_local! {
+    static COUNTER: Cell<Counter> =
+        const { Cell::new(Counter { allocations: 0, allocated: 0, live: 0, peak: 0 }) };
+    /// The highest peak resident memory seen before a nested measurement reset it.
+    static RSS_SEEN: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Count an allocation of `allocated` bytes, which changes the live bytes by `change`.
+fn record(allocated: Option<usize>, change: i64) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    // The thread's counter may already be gone while the thread is being torn down.
+    let _ = COUNTER.try_with(|counter| {
+        let mut c = counter.get();
+        if let Some(bytes) = allocated {
+            c.allocations += 1;
+            c.allocated += bytes as u64;
+        }
+        c.live += change;
+        c.peak = c.peak.max(c.live);
+        counter.set(c);
+    });
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(Some(layout.size()), layout.size() as i64);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(Some(layout.size()), layout.size() as i64);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(None, -(layout.size() as i64));
+    }
+
+    /// A reallocation counts as one allocation of the new size.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record(Some(new_size), new_size as i64 - layout.size() as i64);
+        }
+        new_ptr
+    }
+}
+
+/// Start counting allocations, on every thread.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// What one phase of a day allocated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Allocations {
+    /// How many allocations were made, counting each reallocation as one.
+    pub count: u64,
+    /// The bytes all those allocations asked for, freed or not.
+    pub bytes: u64,
+    /// The most bytes that were allocated and not yet freed at any one time, beyond those
+    /// already live when the phase started. This is peak heap, not resident memory: it leaves
+    /// out the stack, the binary, and memory the allocator holds on to after it is freed.
+    pub peak_bytes: u64,
+    /// The most memory the process had resident at any one time during the phase, where the
+    /// system reports it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peak_rss_bytes: Option<u64>,
+}
+
+/// Bytes in the largest binary unit that keeps the number at least one, e.g. `12.3 KiB`.
+fn format_bytes(bytes: u64) -> String {
+    let units = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < units.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", units[unit])
+    }
+}
+
+impl fmt::Display for Allocations {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, {} peak heap",
+            self.count,
+            format_bytes(self.bytes),
+            format_bytes(self.peak_bytes)
+        )?;
+        if let Some(rss) = self.peak_rss_bytes {
+            write!(f, ", {} peak RSS", format_bytes(rss))?;
+        }
+        Ok(())
+    }
+}
+
+/// What each phase of a day allocated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Memory {
+    pub parse: Allocations,
+    pub solve: Allocations,
+}
+
+/// The peak resident memory of the process so far, from `VmHWM` in `/proc/self/status`.
+fn peak_rss() -> Option<u64> {
+    let status = fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find_map(|line| line.strip_prefix("VmHWM:"))?;
+    let kib: u64 = line.trim().strip_suffix("kB")?.trim().parse().ok()?;
+    Some(kib * 1024)
+}
+
+/// Start the peak resident memory afresh from what is resident now, and return the peak it had.
+/// Kernels that cannot do this keep the peak of the whole run so far.
+fn reset_peak_rss() -> Option<u64> {
+    let peak = peak_rss()?;
+    let _ = fs::write("/proc/self/clear_refs", "5");
+    Some(peak)
+}
+
+/// Call `f`, and count what it allocates on this thread while counting is enabled.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Allocations) {
+    let rss_before = if enabled() { reset_peak_rss() } else { None };
+    let rss_seen = RSS_SEEN.with(|seen| seen.replace(0));
+    let before = COUNTER.with(|counter| {
+        let c = counter.get();
+        counter.set(Counter { peak: c.live, ..c });
+        c
+    });
+    let result = f();
+    let after = COUNTER.with(|counter| {
+        let c = counter.get();
+        // Leave the peak as an enclosing measurement would have seen it.
+        counter.set(Counter { peak: c.peak.max(before.peak), ..c });
+        c
+    });
+    let peak_rss_bytes = rss_before.and(peak_rss()).map(|peak| peak.max(RSS_SEEN.with(Cell::get)));
+    // Leave the peaks the reset hid where an enclosing measurement will find them.
+    let seen = [rss_seen, rss_before.unwrap_or_default(), peak_rss_bytes.unwrap_or_default()];
+    RSS_SEEN.with(|cell| cell.set(seen.into_iter().max().unwrap_or_default()));
+    let allocations = Allocations {
+        count: after.allocations - before.allocations,
+        bytes: after.allocated - before.allocated,
+        peak_bytes: (after.peak - before.live).max(0) as u64,
+        peak_rss_bytes,
+    };
+    (result, allocations)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn test_measure() {
+        enable();
+        let (sum, allocations) = measure(|| {
+            let kept: Vec<u64> = (0..1000).collect();
+            let dropped = vec![0_u8; 4096];
+            drop(dropped);
+            kept.iter().sum::<u64>()
+        });
+        assert_eq!(sum, 499500);
+        assert!(allocations.count >= 2, "{allocations:?}");
+        assert!(allocations.bytes >= 8000 + 4096, "{allocations:?}");
+        assert!(allocations.peak_bytes >= 8000 + 4096, "{allocations:?}");
+    }
+
+    #[test]
+    fn test_measure_nested() {
+        enable();
+        let (inner, outer) = measure(|| {
+            let big = vec![0_u8; 1 << 16];
+            drop(big);
+            measure(|| vec![0_u8; 16]).1
+        });
+        assert!(inner.peak_bytes < 1 << 16, "{inner:?}");
+        assert!(outer.peak_bytes >= 1 << 16, "{outer:?}");
+        assert!(outer.count > inner.count);
+    }
+
+    #[test]
+    fn test_measure_peak_rss() {
+        enable();
+        let (_, outer) = measure(|| {
+            let big = vec![1_u8; 64 << 20];
+            let inner = measure(|| ()).1;
+            drop(big);
+            inner
+        });
+        let Some(peak) = outer.peak_rss_bytes else { return };
+        assert!(peak >= 64 << 20, "{outer:?}");
+    }
+
+    #[test]
+    fn test_display() {
+        let mut allocations =
+            Allocations { count: 3, bytes: 1536, peak_bytes: 100, peak_rss_bytes: None };
+        assert_eq!(allocations.to_string(), "3 allocations, 1.5 KiB allocated, 100 B peak heap");
+        allocations.peak_rss_bytes = Some(3 << 20);
+        assert_eq!(
+            allocations.to_string(),
+            "3 allocations, 1.5 KiB allocated, 100 B peak heap, 3.0 MiB peak RSS"
+        );
+        assert_eq!(format_bytes(5 << 20), "5.0 MiB");
+    }
+}
@@ -0,0 +1,123 @@
+use std::time::Duration;
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::commands::{Answer, DynError, PuzzleId, Run};
+use crate::memory::Memory;
+
+/// How results are written to stdout.
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Human-readable text
+    #[default]
+    Text,
+    /// One JSON object per line
+    Json,
+}
+
+/// A duration in milliseconds, the unit every report uses.
+pub fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+/// The JSON object written for a day that ran.
+#[derive(Debug, Serialize)]
+pub struct DayReport<'a> {
+    pub year: u16,
+    pub day: u8,
+    pub part1: Option<&'a Answer>,
+    pub part2: Option<&'a Answer>,
+    pub parse_ms: f64,
+    pub solve_ms: f64,
+    #[serde(skip_serializing_if = "<[String]>::is_empty")]
+    pub diagnostics: &'a [String],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<&'a Memory>,
+}
+
+impl<'a> DayReport<'a> {
+    pub fn new(id: PuzzleId, run: &'a Run) -> Self {
+        Self {
+            year: id.year,
+            day: id.day,
+            part1: run.solution.part1.as_ref(),
+            part2: run.solution.part2.as_ref(),
+            parse_ms: millis(run.timings.parse),
+            solve_ms: millis(run.timings.solve),
+            diagnostics: &run.solution.diagnostics,
+            memory: None,
+        }
+    }
+
+    /// Also report what each phase allocated, for `--mem-stats`.
+    pub fn with_memory(mut self, run: &'a Run) -> Self {
+        self.memory = Some(&run.memory);
+        self
+    }
+}
+
+/// The JSON object written for a day that could not be run.
+#[derive(Debug, Serialize)]
+pub struct FailureReport<'a> {
+    pub year: u16,
+    pub day: u8,
+    pub error: &'a str,
+}
+
+impl<'a> FailureReport<'a> {
+    pub fn new(id: PuzzleId, error: &'a str) -> Self {
+        Self { year: id.year, day: id.day, error }
+    }
+}
+
+/// Write `value` as a single line of JSON.
+pub fn print_json<T: Serialize>(value: &T) -> Result<(), DynError> {
+    println!("{}", serde_json::to_string(value)?);
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::{Solution, Timings};
+    use crate::memory::Allocations;
+
+    #[test]
+    fn test_day_report() -> Result<(), Box<dyn std::error::Error>> {
+        let run = Run {
+            solution: Solution::new().with_part1(42).with_part2("4,6,3").with_diagnostic("note"),
+            timings: Timings {
+                parse: Duration::from_micros(1500),
+                solve: Duration::from_millis(2),
+            },
+            ..Run::default()
+        };
+        let observed = serde_json::to_string(&DayReport::new(PuzzleId::new(2024, 17), &run))?;
+        let expected = r#"{"year":2024,"day":17,"part1":42,"part2":"4,6,3","parse_ms":1.5,"solve_ms":2.0,"diagnostics":["note"]}"#;
+        assert_eq!(observed, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_day_report_without_diagnostics() -> Result<(), Box<dyn std::error::Error>> {
+        let run = Run { solution: Solution::new().with_part2(7), ..Run::default() };
+        let observed = serde_json::to_string(&DayReport::new(PuzzleId::new(2022, 1), &run))?;
+        let expected =
+            r#"{"year":2022,"day":1,"part1":null,"part2":7,"parse_ms":0.0,"solve_ms":0.0}"#;
+        assert_eq!(observed, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_day_report_with_memory() -> Result<(), Box<dyn std::error::Error>> {
+        let mut run = Run::default();
+        run.memory.solve =
+            Allocations { count: 2, bytes: 64, peak_bytes: 48, peak_rss_bytes: Some(4096) };
+        let report = DayReport::new(PuzzleId::new(2024, 11), &run).with_memory(&run);
+        let observed = serde_json::to_string(&report)?;
+        let expected = r#"{"year":2024,"day":11,"part1":null,"part2":null,"parse_ms":0.0,"solve_ms":0.0,"memory":{"parse":{"count":0,"bytes":0,"peak_bytes":0},"solve":{"count":2,"bytes":64,"peak_bytes":48,"peak_rss_bytes":4096}}}"#;
+        assert_eq!(observed, expected);
+        Ok(())
+    }
+}
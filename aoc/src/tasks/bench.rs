@@ -0,0 +1,234 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::Parser;
+use serde::Serialize;
+
+use super::{guarded, TaskImpl};
+use crate::commands::{default_input, input_in, CommandImpl, Day, DynError, PuzzleId, Timings};
+use crate::output::{millis, print_json, FailureReport, Format};
+
+/// Run one day, or every day, repeatedly and report timing statistics
+#[derive(Parser, Debug)]
+pub struct Bench {
+    /// Day to benchmark, such as `2024/day16`, or `all` for every registered day
+    #[clap(long, short, default_value = "all")]
+    day: String,
+    /// Directory holding one `<year>/day<N>.txt` input per day [default: $AOC_INPUT_DIR or
+    /// aoc/inputs]
+    #[clap(long, short)]
+    inputs: Option<PathBuf>,
+    /// Number of measured runs
+    #[clap(long, short, default_value_t = 10)]
+    runs: usize,
+    /// Number of unmeasured runs made first
+    #[clap(long, short, default_value_t = 2)]
+    warmup: usize,
+}
+
+/// Summary statistics over a set of durations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Summarise `samples`, or `None` when there are none.
+    pub fn new(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let median = (sorted[(n - 1) / 2] + sorted[n / 2]) / 2;
+        // Nearest-rank percentile: the ceil(0.95 * n)-th smallest sample.
+        let p95 = sorted[n - n * 5 / 100 - 1];
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance =
+            sorted.iter().map(|d| (d.as_secs_f64() - mean).powi(2)).sum::<f64>() / n as f64;
+        Some(Self {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            p95,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.3}ms", millis(duration))
+}
+
+impl Bench {
+    fn input_for(&self, id: PuzzleId) -> PathBuf {
+        match &self.inputs {
+            Some(dir) => input_in(dir, id),
+            None => default_input(id),
+        }
+    }
+
+    fn ids(&self) -> Result<Vec<PuzzleId>, DynError> {
+        if self.day == "all" {
+            return Ok(Day::ids().to_vec());
+        }
+        let id: PuzzleId = self.day.parse()?;
+        match Day::ids().contains(&id) {
+            true => Ok(vec![id]),
+            false => Err(format!("unknown day {id}").into()),
+        }
+    }
+
+    /// Time every measured run of `id`, after the warm-up runs.
+    fn measure(&self, id: PuzzleId) -> Result<Vec<Timings>, DynError> {
+        let path = self.input_for(id);
+        let day = Day::with_input(id, &path)?;
+        let input = fs::read_to_string(&path)?;
+        for _ in 0..self.warmup {
+            day.run(&input)?;
+        }
+        (0..self.runs).map(|_| day.run(&input).map(|run| run.timings)).collect()
+    }
+}
+
+/// Statistics for one phase, in milliseconds, as written in JSON mode.
+#[derive(Debug, Serialize)]
+struct StatsReport {
+    min_ms: f64,
+    median_ms: f64,
+    mean_ms: f64,
+    p95_ms: f64,
+    stddev_ms: f64,
+}
+
+impl From<Stats> for StatsReport {
+    fn from(stats: Stats) -> Self {
+        Self {
+            min_ms: millis(stats.min),
+            median_ms: millis(stats.median),
+            mean_ms: millis(stats.mean),
+            p95_ms: millis(stats.p95),
+            stddev_ms: millis(stats.stddev),
+        }
+    }
+}
+
+/// The JSON object written for a benchmarked day.
+#[derive(Debug, Serialize)]
+struct BenchReport {
+    year: u16,
+    day: u8,
+    runs: usize,
+    parse: StatsReport,
+    solve: StatsReport,
+}
+
+fn print_stats_row(id: PuzzleId, phase: &str, stats: &Stats) {
+    println!(
+        "{:<11}  {:<5}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}",
+        id.to_string(),
+        phase,
+        format_duration(stats.min),
+        format_duration(stats.median),
+        format_duration(stats.mean),
+        format_duration(stats.p95),
+        format_duration(stats.stddev),
+    );
+}
+
+impl TaskImpl for Bench {
+    fn main(&self, format: Format) -> Result<(), DynError> {
+        if self.runs == 0 {
+            return Err("--runs must be at least 1".into());
+        }
+        let ids = self.ids()?;
+
+        if format == Format::Text {
+            let header = ["day", "phase", "min", "median", "mean", "p95", "stddev"];
+            println!(
+                "{:<11}  {:<5}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}",
+                header[0], header[1], header[2], header[3], header[4], header[5], header[6]
+            );
+        }
+        let mut skipped: Vec<(PuzzleId, String)> = Vec::new();
+        for id in ids {
+            if !self.input_for(id).is_file() {
+                skipped.push((id, "missing input".to_string()));
+                continue;
+            }
+            let timings = match guarded(|| self.measure(id)) {
+                Ok(timings) => timings,
+                Err(error) => {
+                    skipped.push((id, error));
+                    continue;
+                }
+            };
+            let parse: Vec<Duration> = timings.iter().map(|t| t.parse).collect();
+            let solve: Vec<Duration> = timings.iter().map(|t| t.solve).collect();
+            let (Some(parse), Some(solve)) = (Stats::new(&parse), Stats::new(&solve)) else {
+                continue;
+            };
+            match format {
+                Format::Text => {
+                    print_stats_row(id, "parse", &parse);
+                    print_stats_row(id, "solve", &solve);
+                }
+                Format::Json => print_json(&BenchReport {
+                    year: id.year,
+                    day: id.day,
+                    runs: self.runs,
+                    parse: parse.into(),
+                    solve: solve.into(),
+                })?,
+            }
+        }
+
+        match format {
+            Format::Text if !skipped.is_empty() => {
+                println!("\nskipped:");
+                for (id, reason) in skipped.iter() {
+                    println!("  {id}: {reason}");
+                }
+            }
+            Format::Text => {}
+            Format::Json => {
+                for (id, reason) in skipped.iter() {
+                    print_json(&FailureReport::new(*id, reason))?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_stats() -> Result<(), Box<dyn std::error::Error>> {
+        let samples: Vec<Duration> =
+            [4, 1, 3, 2, 5].iter().map(|&ms| Duration::from_millis(ms)).collect();
+        let stats = Stats::new(&samples).ok_or("no stats")?;
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(3));
+        assert_eq!(stats.p95, Duration::from_millis(5));
+        assert!((stats.stddev.as_secs_f64() - 0.002f64.sqrt() / 1000f64.sqrt()).abs() < 1e-9);
+        Ok(())
+    }
+
+    #[test]
+    fn test_stats_even_median() {
+        let samples: Vec<Duration> =
+            [1, 2, 3, 4].iter().map(|&ms| Duration::from_millis(ms)).collect();
+        assert_eq!(Stats::new(&samples).map(|s| s.median), Some(Duration::from_micros(2500)));
+        assert_eq!(Stats::new(&[]), None);
+    }
+}
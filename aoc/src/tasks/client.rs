@@ -0,0 +1,152 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::commands::DynError;
+
+/// Environment variable holding the session cookie of a logged-in Advent of Code user.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// Environment variable naming the server to talk to instead of the real one.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The server to use: `url` if given, then `$AOC_BASE_URL`, then the real one.
+pub fn base_url(url: Option<&str>) -> String {
+    url.map(str::to_string)
+        .or_else(|| env::var(BASE_URL_ENV).ok())
+        .unwrap_or_else(|| DEFAULT_BASE_URL.to_string())
+}
+
+/// The config file a session token is read from when `$AOC_SESSION` is not set:
+/// `$XDG_CONFIG_HOME/aoc/session`, or `~/.config/aoc/session`.
+pub fn session_file() -> Option<PathBuf> {
+    let config = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config.join("aoc").join("session"))
+}
+
+/// The session token from `$AOC_SESSION` or, failing that, the [`session_file`].
+pub fn session_token() -> Result<String, DynError> {
+    if let Ok(token) = env::var(SESSION_ENV) {
+        if !token.trim().is_empty() {
+            return Ok(token.trim().to_string());
+        }
+    }
+    let path = session_file().ok_or("no home directory to find a session file in")?;
+    match fs::read_to_string(&path) {
+        Ok(token) if !token.trim().is_empty() => Ok(token.trim().to_string()),
+        _ => Err(format!(
+            "no session token: set {SESSION_ENV} or save the `session` cookie to {}",
+            path.display()
+        )
+        .into()),
+    }
+}
+
+/// Talks to the Advent of Code server on behalf of one logged-in user.
+#[derive(Debug)]
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(concat!("aoc/", env!("CARGO_PKG_VERSION")))
+            .build();
+        let base_url = base_url.into().trim_end_matches('/').to_string();
+        Self { base_url, session: session.into(), agent }
+    }
+
+    fn url(&self, year: u16, day: u8, page: &str) -> String {
+        format!("{}/{year}/day/{day}{page}", self.base_url)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// The puzzle input of `day` in `year`.
+    pub fn input(&self, year: u16, day: u8) -> Result<String, DynError> {
+        let url = self.url(year, day, "/input");
+        let response = self.agent.get(&url).set("Cookie", &self.cookie()).call();
+        read_body(&url, response)
+    }
+
+    /// Post `answer` to part `level` of `day` in `year`, returning the page the server answers with.
+    pub fn submit(&self, year: u16, day: u8, level: u8, answer: &str) -> Result<String, DynError> {
+        let url = self.url(year, day, "/answer");
+        let level = level.to_string();
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &level), ("answer", answer)]);
+        read_body(&url, response)
+    }
+}
+
+/// The body of a successful response, or an error saying what the server answered.
+fn read_body(url: &str, response: Result<ureq::Response, ureq::Error>) -> Result<String, DynError> {
+    match response {
+        Ok(response) => Ok(response.into_string()?),
+        Err(ureq::Error::Status(status, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            let reason = body.lines().next().unwrap_or_default().trim();
+            let hint = if status == 400 { " (is the session token still valid?)" } else { "" };
+            Err(format!("{url} answered {status}{hint}: {reason}").into())
+        }
+        Err(error) => Err(error.into()),
+    }
+}
+
+/// A local stand-in for the puzzle server, for testing the commands that talk to it.
+#[cfg(test)]
+pub(crate) mod stand_in {
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    use tiny_http::{Header, Response, Server};
+
+    /// What the stand-in was sent.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Received {
+        pub url: String,
+        pub cookie: String,
+        pub body: String,
+    }
+
+    pub type Log = Arc<Mutex<Vec<Received>>>;
+
+    /// Serve every request with `status` and `body`, returning the base URL and a log of requests.
+    pub fn serve(status: u16, body: &'static str) -> (String, Log) {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let received = Arc::clone(&log);
+        thread::spawn(move || {
+            for mut request in server.incoming_requests() {
+                let cookie = request
+                    .headers()
+                    .iter()
+                    .find(|header| header.field.equiv("Cookie"))
+                    .map(|header| header.value.to_string())
+                    .unwrap_or_default();
+                let mut sent = String::new();
+                request.as_reader().read_to_string(&mut sent).unwrap();
+                let url = request.url().to_string();
+                received.lock().unwrap().push(Received { url, cookie, body: sent });
+                let header = Header::from_bytes("Content-Type", "text/html").unwrap();
+                let response =
+                    Response::from_string(body).with_status_code(status).with_header(header);
+                request.respond(response).unwrap();
+            }
+        });
+        (url, log)
+    }
+}
@@ -0,0 +1,151 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use clap::Parser;
+use serde::Serialize;
+
+use super::client::{base_url, session_token, Client};
+use super::TaskImpl;
+use crate::commands::{input_dir, input_in, DynError, PuzzleId};
+use crate::output::{print_json, Format};
+
+/// Download a day's puzzle input into the inputs directory, unless it is already there
+#[derive(Parser, Debug)]
+pub struct Fetch {
+    /// Day of the puzzle
+    #[clap(long, short, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Year of the puzzle
+    #[clap(long, short, default_value_t = 2024)]
+    year: u16,
+    /// Directory to save `<year>/day<N>.txt` in [default: $AOC_INPUT_DIR or aoc/inputs]
+    #[clap(long, short)]
+    inputs: Option<PathBuf>,
+    /// Server to download from [default: $AOC_BASE_URL or https://adventofcode.com]
+    #[clap(long)]
+    base_url: Option<String>,
+}
+
+/// Whether an input had to be downloaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+/// Make sure the input of `day` in `year` is saved at `path`, downloading it with the client
+/// `connect` makes only when it is not there yet. An empty file, such as the one `new-day`
+/// leaves, does not count as a saved input.
+pub fn fetch_input(
+    path: &Path,
+    year: u16,
+    day: u8,
+    connect: impl FnOnce() -> Result<Client, DynError>,
+) -> Result<Fetched, DynError> {
+    if fs::metadata(path).is_ok_and(|metadata| metadata.is_file() && metadata.len() > 0) {
+        return Ok(Fetched::Cached);
+    }
+    let input = connect()?.input(year, day)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    // Write to the side first, so an interrupted download is never mistaken for a cached input.
+    let partial = path.with_extension("part");
+    fs::write(&partial, input)?;
+    fs::rename(&partial, path)?;
+    Ok(Fetched::Downloaded)
+}
+
+/// The JSON object written for a fetched input.
+#[derive(Debug, Serialize)]
+struct FetchReport {
+    year: u16,
+    day: u8,
+    path: String,
+    cached: bool,
+}
+
+impl TaskImpl for Fetch {
+    fn main(&self, format: Format) -> Result<(), DynError> {
+        let dir = self.inputs.clone().unwrap_or_else(input_dir);
+        let path = input_in(&dir, PuzzleId::new(self.year, self.day));
+        let fetched = fetch_input(&path, self.year, self.day, || {
+            Ok(Client::new(base_url(self.base_url.as_deref()), session_token()?))
+        })?;
+
+        match format {
+            Format::Text => match fetched {
+                Fetched::Cached => println!("{} is already cached", path.display()),
+                Fetched::Downloaded => {
+                    println!("saved {} day {} to {}", self.year, self.day, path.display())
+                }
+            },
+            Format::Json => print_json(&FetchReport {
+                year: self.year,
+                day: self.day,
+                path: path.display().to_string(),
+                cached: fetched == Fetched::Cached,
+            })?,
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tasks::client::stand_in::{serve, Received};
+
+    #[test]
+    fn test_fetch_downloads_once() -> Result<(), Box<dyn std::error::Error>> {
+        let (url, requests) = serve(200, "1 2\n3 4\n");
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("inputs").join("day3.txt");
+        let connect = || Ok(Client::new(url.clone(), "secret"));
+
+        assert_eq!(fetch_input(&path, 2024, 3, connect)?, Fetched::Downloaded);
+        assert_eq!(fs::read_to_string(&path)?, "1 2\n3 4\n");
+        assert_eq!(fetch_input(&path, 2024, 3, connect)?, Fetched::Cached);
+        let expected = Received {
+            url: "/2024/day/3/input".to_string(),
+            cookie: "session=secret".to_string(),
+            body: String::new(),
+        };
+        assert_eq!(*requests.lock().unwrap(), vec![expected]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_cached_input_needs_no_session() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("day1.txt");
+        fs::write(&path, "cached")?;
+        let fetched = fetch_input(&path, 2024, 1, || Err("no session token".into()))?;
+        assert_eq!(fetched, Fetched::Cached);
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty_input_is_downloaded() -> Result<(), Box<dyn std::error::Error>> {
+        let (url, requests) = serve(200, "7 6 4 2 1\n");
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("day2.txt");
+        fs::write(&path, "")?;
+        let fetched = fetch_input(&path, 2024, 2, || Ok(Client::new(url, "secret")))?;
+        assert_eq!(fetched, Fetched::Downloaded);
+        assert_eq!(fs::read_to_string(&path)?, "7 6 4 2 1\n");
+        assert_eq!(requests.lock().unwrap().len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fetch_reports_server_errors() -> Result<(), Box<dyn std::error::Error>> {
+        let (url, _) = serve(400, "Puzzle inputs differ by user.  Please log in.\n");
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("day9.txt");
+        let error = fetch_input(&path, 2024, 9, || Ok(Client::new(url, "expired"))).unwrap_err();
+        assert!(error.to_string().contains("answered 400"), "{error}");
+        assert!(!path.exists());
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use std::fs;
+use std::path::PathBuf;
+
+use clap::Parser;
+use serde::Serialize;
+
+use super::TaskImpl;
+use crate::commands::{CommandImpl, Day, DynError, PuzzleId};
+use crate::output::{print_json, Format};
+
+/// Make up a random, well-formed input for a day, for stress testing
+#[derive(Parser, Debug)]
+pub struct Gen {
+    /// Day of the puzzle to generate an input for
+    #[clap(long, short, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Year of the puzzle
+    #[clap(long, short, default_value_t = 2024)]
+    year: u16,
+    /// Seed for the generator; the same seed always gives the same input
+    #[clap(long, short, default_value_t = 0)]
+    seed: u64,
+    /// How big to make the input, such as the side of a grid or the number of lines
+    /// [default: about the size of a real input]
+    #[clap(long)]
+    size: Option<usize>,
+    /// File to write the input to [default: stdout]
+    #[clap(long, short)]
+    output: Option<PathBuf>,
+}
+
+impl Gen {
+    fn id(&self) -> PuzzleId {
+        PuzzleId::new(self.year, self.day)
+    }
+
+    /// The input the options describe.
+    pub fn generate(&self) -> Result<String, DynError> {
+        let id = self.id();
+        if !Day::ids().contains(&id) {
+            return Err(format!("unknown day {id}").into());
+        }
+        Day::from_id(id)?
+            .generate_input(self.seed, self.size)
+            .ok_or_else(|| format!("{id} has no input generator").into())
+    }
+}
+
+/// The JSON object written for a generated input; the input itself goes to the output file.
+#[derive(Debug, Serialize)]
+struct GenReport {
+    year: u16,
+    day: u8,
+    seed: u64,
+    size: Option<usize>,
+    path: String,
+    bytes: usize,
+}
+
+impl TaskImpl for Gen {
+    fn main(&self, format: Format) -> Result<(), DynError> {
+        let input = self.generate()?;
+        let Some(path) = &self.output else {
+            print!("{input}");
+            return Ok(());
+        };
+        fs::write(path, &input)?;
+        match format {
+            Format::Text => println!("wrote {} bytes to {}", input.len(), path.display()),
+            Format::Json => print_json(&GenReport {
+                year: self.year,
+                day: self.day,
+                seed: self.seed,
+                size: self.size,
+                path: path.display().to_string(),
+                bytes: input.len(),
+            })?,
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_day_by_year() -> Result<(), Box<dyn std::error::Error>> {
+        let generate = |args: &[&str]| -> Result<String, DynError> {
+            Gen::try_parse_from(["gen", "--size", "9", "--seed", "3"].iter().chain(args))?
+                .generate()
+        };
+        assert_eq!(generate(&["--day", "16"])?, generate(&["--day", "16", "--year", "2024"])?);
+        assert_ne!(generate(&["--day", "1", "--year", "2022"])?, generate(&["--day", "1"])?);
+        let unknown = generate(&["--day", "1", "--year", "1999"]).unwrap_err();
+        assert!(unknown.to_string().contains("unknown day 1999/day1"));
+        assert!(generate(&["--day", "26"]).is_err());
+        Ok(())
+    }
+}
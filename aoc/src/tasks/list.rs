@@ -0,0 +1,160 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+use serde::Serialize;
+
+use super::submit::Ledger;
+use super::TaskImpl;
+use crate::commands::{default_input, input_in, DynError, PuzzleId, Registration};
+use crate::output::{print_json, Format};
+
+/// List every registered day with its puzzle, its input, and the stars earned for it
+#[derive(Parser, Debug)]
+pub struct List {
+    /// Directory holding one `<year>/day<N>.txt` input per day [default: $AOC_INPUT_DIR or
+    /// aoc/inputs]
+    #[clap(long, short)]
+    inputs: Option<PathBuf>,
+    /// File every guess and its verdict is recorded in, which the stars are read from
+    #[clap(long, default_value = "aoc/guesses.jsonl")]
+    ledger: PathBuf,
+}
+
+/// The JSON object written for each registered day.
+#[derive(Debug, Serialize)]
+pub struct ListReport {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    /// The parts the solver answers.
+    pub parts: Vec<u8>,
+    pub input: String,
+    pub has_input: bool,
+    /// The parts whose answer the ledger records as accepted.
+    pub stars: Vec<u8>,
+}
+
+impl List {
+    /// What is known about `registration` from the input directory and the ledger.
+    pub fn report(&self, registration: &Registration, ledger: &Ledger) -> ListReport {
+        let puzzle = registration.puzzle;
+        let input = match &self.inputs {
+            Some(dir) => input_in(dir, puzzle.id()),
+            None => default_input(puzzle.id()),
+        };
+        let parts = [(1, puzzle.parts.part1()), (2, puzzle.parts.part2())];
+        ListReport {
+            year: puzzle.year,
+            day: puzzle.day,
+            title: puzzle.title,
+            parts: parts.into_iter().filter(|&(_, answered)| answered).map(|(p, _)| p).collect(),
+            input: input.display().to_string(),
+            has_input: input.is_file(),
+            stars: [1, 2]
+                .into_iter()
+                .filter(|&p| ledger.is_solved(puzzle.year, puzzle.day, p))
+                .collect(),
+        }
+    }
+}
+
+fn join(parts: &[u8]) -> String {
+    let parts: Vec<String> = parts.iter().map(u8::to_string).collect();
+    parts.join(",")
+}
+
+/// Print one row per day, with the columns padded to line up.
+fn print_table(reports: &[ListReport]) {
+    let header = ["day", "title", "parts", "input", "stars"];
+    let rows: Vec<[String; 5]> = reports
+        .iter()
+        .map(|report| {
+            [
+                PuzzleId::new(report.year, report.day).to_string(),
+                report.title.to_string(),
+                join(&report.parts),
+                if report.has_input { "yes" } else { "missing" }.to_string(),
+                if report.stars.is_empty() {
+                    "-".to_string()
+                } else {
+                    "*".repeat(report.stars.len())
+                },
+            ]
+        })
+        .collect();
+
+    let mut widths = header.map(str::len);
+    for row in rows.iter() {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.len());
+        }
+    }
+    let print_row = |cells: [&str; 5]| {
+        let cells: Vec<String> = cells
+            .iter()
+            .zip(widths.iter())
+            .map(|(cell, &width)| format!("{cell:<width$}"))
+            .collect();
+        println!("{}", cells.join("  ").trim_end());
+    };
+    print_row(header);
+    for row in rows.iter() {
+        print_row([&row[0], &row[1], &row[2], &row[3], &row[4]]);
+    }
+}
+
+impl TaskImpl for List {
+    fn main(&self, format: Format) -> Result<(), DynError> {
+        let ledger = Ledger::load(&self.ledger)?;
+        let reports: Vec<ListReport> = Registration::all()
+            .iter()
+            .map(|registration| self.report(registration, &ledger))
+            .collect();
+
+        match format {
+            Format::Text => print_table(&reports),
+            Format::Json => {
+                for report in reports.iter() {
+                    print_json(report)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tasks::submit::{Guess, Verdict};
+
+    #[test]
+    fn test_report() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = tempfile::tempdir()?;
+        std::fs::create_dir(dir.path().join("2024"))?;
+        std::fs::write(dir.path().join("2024").join("day20.txt"), "#S.E#\n")?;
+        let list = List::try_parse_from(["list", "--inputs", dir.path().to_str().unwrap()])?;
+        let ledger = Ledger {
+            guesses: vec![Guess {
+                year: 2024,
+                day: 20,
+                part: 2,
+                answer: "1".to_string(),
+                verdict: Verdict::Correct,
+                submitted_at: None,
+            }],
+        };
+
+        let day20 = list.report(Registration::find(PuzzleId::new(2024, 20)).unwrap(), &ledger);
+        assert_eq!((day20.year, day20.day, day20.title), (2024, 20, "Race Condition"));
+        assert_eq!(day20.parts, vec![2]);
+        assert!(day20.has_input);
+        assert_eq!(day20.stars, vec![2]);
+
+        let day16 = list.report(Registration::find(PuzzleId::new(2024, 16)).unwrap(), &ledger);
+        assert_eq!(day16.parts, vec![1, 2]);
+        assert!(!day16.has_input);
+        assert!(day16.stars.is_empty());
+        Ok(())
+    }
+}
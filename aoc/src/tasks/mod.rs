@@ -0,0 +1,155 @@
+pub mod bench;
+pub mod client;
+pub mod fetch;
+pub mod gen;
+pub mod list;
+pub mod repl;
+pub mod run_all;
+pub mod submit;
+pub mod verify;
+pub mod watch;
+
+use std::cell::Cell;
+use std::num::NonZeroUsize;
+use std::panic;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, Once};
+use std::thread;
+
+use enum_dispatch::enum_dispatch;
+
+use clap::Subcommand;
+
+use crate::commands::{Day, DynError, PuzzleId, Run};
+use crate::output::Format;
+
+/// Commands that work across days rather than solving one of them.
+#[enum_dispatch]
+pub trait TaskImpl {
+    fn main(&self, format: Format) -> Result<(), DynError>;
+}
+
+#[enum_dispatch(TaskImpl)]
+#[derive(Subcommand, Debug)]
+pub enum Task {
+    Bench(bench::Bench),
+    Fetch(fetch::Fetch),
+    Gen(gen::Gen),
+    List(list::List),
+    Repl(repl::Repl),
+    RunAll(run_all::RunAll),
+    Submit(submit::Submit),
+    Verify(verify::Verify),
+    Watch(watch::Watch),
+}
+
+/// Run a day, turning both errors and panics into a message so one bad day does not stop the rest.
+pub(crate) fn run_guarded(id: PuzzleId, input: &Path) -> Result<Run, String> {
+    guarded(|| Day::with_input(id, input)?.main())
+}
+
+thread_local! {
+    /// Set while this thread is inside [`guarded`], whose panics are reported as errors instead.
+    static GUARDED: Cell<bool> = const { Cell::new(false) };
+}
+
+static SILENCE_GUARDED_PANICS: Once = Once::new();
+
+/// Call `f`, turning both its error and any panic into a message.
+pub(crate) fn guarded<T>(f: impl FnOnce() -> Result<T, DynError>) -> Result<T, String> {
+    // The panic hook is global, so rather than swapping it per call, which races when days run
+    // on several threads, install one hook that stays quiet for guarded threads only.
+    SILENCE_GUARDED_PANICS.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !GUARDED.with(Cell::get) {
+                hook(info);
+            }
+        }));
+    });
+    GUARDED.with(|guarded| guarded.set(true));
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(f));
+    GUARDED.with(|guarded| guarded.set(false));
+
+    match result {
+        Ok(result) => result.map_err(|e| e.to_string()),
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            Err(format!("panicked: {message}"))
+        }
+    }
+}
+
+/// Call `f` on every item on a pool of up to `jobs` threads, one per available CPU by default.
+///
+/// Each result is kept apart from the others until every item is done, and the results come back
+/// in the order of `items` however the work was scheduled.
+pub(crate) fn parallel_map<T, R, F>(items: &[T], jobs: Option<NonZeroUsize>, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let jobs = jobs
+        .or_else(|| thread::available_parallelism().ok())
+        .map_or(1, NonZeroUsize::get)
+        .min(items.len());
+    let next = AtomicUsize::new(0);
+    let results: Vec<Mutex<Option<R>>> = items.iter().map(|_| Mutex::new(None)).collect();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else { break };
+                let result = f(item);
+                *results[index].lock().unwrap() = Some(result);
+            });
+        }
+    });
+
+    results
+        .into_iter()
+        .map(|result| result.into_inner().unwrap().expect("every item is claimed by a worker"))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_parallel_map_keeps_order() {
+        let items: Vec<u64> = (0..32).collect();
+        let jobs = NonZeroUsize::new(4);
+        let squares = parallel_map(&items, jobs, |&i| {
+            // Finish the early items last, so completion order differs from input order.
+            thread::sleep(Duration::from_millis(32 - i));
+            i * i
+        });
+        assert_eq!(squares, items.iter().map(|i| i * i).collect::<Vec<u64>>());
+    }
+
+    #[test]
+    fn test_guarded_panics_stay_on_their_thread() {
+        let items = [false, true, false, true];
+        let results = parallel_map(&items, NonZeroUsize::new(2), |&fail| {
+            guarded(|| if fail { panic!("boom") } else { Ok(1) })
+        });
+        assert_eq!(
+            results,
+            vec![
+                Ok(1),
+                Err("panicked: boom".to_string()),
+                Ok(1),
+                Err("panicked: boom".to_string())
+            ]
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use std::io::{self, IsTerminal};
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use super::TaskImpl;
+use crate::commands::{read_input, CommandImpl, Day, DynError, PuzzleId};
+use crate::output::Format;
+
+/// Parse a day's input once, then explore it with commands read from stdin
+#[derive(Parser, Debug)]
+pub struct Repl {
+    /// Day of the puzzle to explore
+    #[clap(long, short, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Year of the puzzle
+    #[clap(long, short, default_value_t = 2024)]
+    year: u16,
+    /// Input to parse [default: the day's default input]
+    #[clap(long, short)]
+    input: Option<PathBuf>,
+}
+
+impl Repl {
+    /// The day the options name, reading the input they name.
+    pub fn day(&self) -> Result<Day, DynError> {
+        let id = PuzzleId::new(self.year, self.day);
+        if !Day::ids().contains(&id) {
+            return Err(format!("unknown day {id}").into());
+        }
+        match &self.input {
+            Some(input) => Day::with_input(id, input),
+            None => Day::from_id(id),
+        }
+    }
+}
+
+/// The session only ever writes text, so `--format` is ignored.
+impl TaskImpl for Repl {
+    fn main(&self, _format: Format) -> Result<(), DynError> {
+        let day = self.day()?;
+        let input = read_input(&day.input_path())?;
+        // Prompt only someone typing; commands piped in from a file get just the results.
+        let prompt = if io::stdin().is_terminal() {
+            eprintln!("{} {}: type help for the commands", day.id(), day.puzzle().title);
+            format!("{}> ", day.id())
+        } else {
+            String::new()
+        };
+        day.repl(&input, &mut io::stdin().lock(), &mut io::stdout().lock(), &prompt)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_day() -> Result<(), Box<dyn std::error::Error>> {
+        let repl = Repl::try_parse_from(["repl", "--day", "17", "-i", "in.txt"])?;
+        let day = repl.day()?;
+        assert_eq!(day.id(), PuzzleId::new(2024, 17));
+        assert_eq!(day.input_path(), PathBuf::from("in.txt"));
+        let unknown = Repl::try_parse_from(["repl", "--day", "1", "--year", "2015"])?.day();
+        assert!(unknown.unwrap_err().to_string().contains("unknown day 2015/day1"));
+        Ok(())
+    }
+}
@@ -0,0 +1,193 @@
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::Parser;
+
+use super::{parallel_map, run_guarded, TaskImpl};
+use crate::commands::{default_input, input_in, Answer, Day, DynError, PuzzleId, Run};
+use crate::memory;
+use crate::output::{millis, print_json, DayReport, FailureReport, Format};
+
+/// Run every registered day against its input and print a timing table
+#[derive(Parser, Debug)]
+pub struct RunAll {
+    /// Directory holding one `<year>/day<N>.txt` input per day [default: $AOC_INPUT_DIR or
+    /// aoc/inputs]
+    #[clap(long, short)]
+    inputs: Option<PathBuf>,
+    /// Run up to this many days at once [default: one per CPU]
+    #[clap(long, short)]
+    jobs: Option<NonZeroUsize>,
+}
+
+/// What happened when a single day was run.
+enum Outcome {
+    Solved(Run),
+    MissingInput(PathBuf),
+    Failed(String),
+}
+
+impl RunAll {
+    fn input_for(&self, id: PuzzleId) -> PathBuf {
+        match &self.inputs {
+            Some(dir) => input_in(dir, id),
+            None => default_input(id),
+        }
+    }
+
+    fn run_day(&self, id: PuzzleId) -> Outcome {
+        let input = self.input_for(id);
+        if !input.is_file() {
+            return Outcome::MissingInput(input);
+        }
+        match run_guarded(id, &input) {
+            Ok(run) => Outcome::Solved(run),
+            Err(error) => Outcome::Failed(error),
+        }
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.3}ms", millis(duration))
+}
+
+fn answer_or_dash(answer: &Option<Answer>) -> String {
+    answer.as_ref().map_or_else(|| "-".to_string(), |a| a.to_string())
+}
+
+/// Print the answers and timings as a table, followed by the days that did not run.
+fn print_table(outcomes: &[(PuzzleId, Outcome)]) {
+    let mut rows: Vec<[String; 5]> = Vec::new();
+    let mut missing: Vec<(PuzzleId, &PathBuf)> = Vec::new();
+    let mut failed: Vec<(PuzzleId, &String)> = Vec::new();
+    let mut total = Duration::ZERO;
+
+    for &(id, ref outcome) in outcomes.iter() {
+        match outcome {
+            Outcome::Solved(run) => {
+                let parse = format_duration(run.timings.parse);
+                let solve = format_duration(run.timings.solve);
+                total += run.timings.parse + run.timings.solve;
+                let parts = [("1", &run.solution.part1), ("2", &run.solution.part2)];
+                for (part, answer) in parts {
+                    let answer = answer_or_dash(answer);
+                    rows.push([
+                        id.to_string(),
+                        part.to_string(),
+                        answer,
+                        parse.clone(),
+                        solve.clone(),
+                    ]);
+                }
+            }
+            Outcome::MissingInput(path) => missing.push((id, path)),
+            Outcome::Failed(error) => failed.push((id, error)),
+        }
+    }
+
+    let header = ["day", "part", "answer", "parse", "solve"];
+    let mut widths = header.map(str::len);
+    for row in rows.iter() {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.len());
+        }
+    }
+    let print_row = |cells: [&str; 5]| {
+        println!(
+            "{:<w0$}  {:<w1$}  {:<w2$}  {:>w3$}  {:>w4$}",
+            cells[0],
+            cells[1],
+            cells[2],
+            cells[3],
+            cells[4],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+            w4 = widths[4],
+        );
+    };
+    print_row(header);
+    for row in rows.iter() {
+        print_row([&row[0], &row[1], &row[2], &row[3], &row[4]]);
+    }
+    println!("total: {}", format_duration(total));
+
+    if memory::enabled() {
+        println!("\nmemory:");
+        let width = outcomes.iter().map(|(id, _)| id.to_string().len()).max().unwrap_or_default();
+        for (id, outcome) in outcomes.iter() {
+            if let Outcome::Solved(run) = outcome {
+                println!("  {:<width$}  parse: {}", id.to_string(), run.memory.parse);
+                println!("  {:<width$}  solve: {}", "", run.memory.solve);
+            }
+        }
+    }
+
+    if !missing.is_empty() {
+        println!("\nmissing input:");
+        for (id, path) in missing.iter() {
+            println!("  {id}: {}", path.display());
+        }
+    }
+    if !failed.is_empty() {
+        println!("\nfailed:");
+        for (id, error) in failed.iter() {
+            println!("  {id}: {error}");
+        }
+    }
+}
+
+impl TaskImpl for RunAll {
+    fn main(&self, format: Format) -> Result<(), DynError> {
+        let ids = Day::ids();
+        let outcomes = parallel_map(ids, self.jobs, |&id| self.run_day(id));
+        let outcomes: Vec<(PuzzleId, Outcome)> = ids.iter().copied().zip(outcomes).collect();
+
+        match format {
+            Format::Text => print_table(&outcomes),
+            Format::Json => {
+                for &(id, ref outcome) in outcomes.iter() {
+                    match outcome {
+                        Outcome::Solved(run) if memory::enabled() => {
+                            print_json(&DayReport::new(id, run).with_memory(run))?
+                        }
+                        Outcome::Solved(run) => print_json(&DayReport::new(id, run))?,
+                        Outcome::MissingInput(path) => {
+                            let error = format!("missing input {}", path.display());
+                            print_json(&FailureReport::new(id, &error))?
+                        }
+                        Outcome::Failed(error) => print_json(&FailureReport::new(id, error))?,
+                    }
+                }
+            }
+        }
+
+        let failures = outcomes.iter().filter(|(_, o)| matches!(o, Outcome::Failed(_))).count();
+        if failures > 0 {
+            return Err(format!("{failures} of {} days failed", outcomes.len()).into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_failed_day_is_an_error() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = tempfile::tempdir()?;
+        let inputs = dir.path().to_str().unwrap();
+        let run_all = RunAll::try_parse_from(["run-all", "--inputs", inputs])?;
+        assert!(run_all.main(Format::Json).is_ok(), "missing inputs are not failures");
+
+        fs::create_dir_all(dir.path().join("2024"))?;
+        fs::write(dir.path().join("2024").join("day16.txt"), "not a maze\n")?;
+        let error = run_all.main(Format::Json).unwrap_err();
+        assert!(error.to_string().starts_with("1 of "), "{error}");
+        Ok(())
+    }
+}
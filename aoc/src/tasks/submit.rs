@@ -0,0 +1,402 @@
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use clap::Parser;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::client::{base_url, session_token, Client};
+use super::TaskImpl;
+use crate::commands::{Day, DynError, PuzzleId};
+use crate::output::{print_json, Format};
+use crate::utils::ParseError;
+
+/// Submit an answer, unless the guess ledger already rules it out
+#[derive(Parser, Debug)]
+pub struct Submit {
+    /// Day of the puzzle
+    #[clap(long, short, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Part of the puzzle the answer is for
+    #[clap(long, short, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Year of the puzzle
+    #[clap(long, short, default_value_t = 2024)]
+    year: u16,
+    /// Answer to submit [default: what the day's solver gives for its default input]
+    answer: Option<String>,
+    /// File every guess and its verdict is recorded in
+    #[clap(long, default_value = "aoc/guesses.jsonl")]
+    ledger: PathBuf,
+    /// Server to submit to [default: $AOC_BASE_URL or https://adventofcode.com]
+    #[clap(long)]
+    base_url: Option<String>,
+}
+
+/// What the server made of an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without saying which way.
+    Wrong,
+    /// Answered too soon after the last wrong answer; nothing was checked.
+    RateLimited {
+        wait_secs: u64,
+    },
+    /// The part is already solved, or not unlocked yet.
+    WrongLevel,
+    /// A page the verdict could not be read from.
+    Unknown,
+}
+
+impl Verdict {
+    /// Read the verdict from the page the server answers a submission with.
+    pub fn parse(page: &str) -> Self {
+        if page.contains("That's the right answer") {
+            Verdict::Correct
+        } else if page.contains("You gave an answer too recently") {
+            let wait = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+            let wait_secs = wait.captures(page).map_or(0, |captures| {
+                let number = |i| captures.get(i).map_or(0, |m| m.as_str().parse().unwrap_or(0));
+                number(1) * 60 + number(2)
+            });
+            Verdict::RateLimited { wait_secs }
+        } else if page.contains("That's not the right answer") {
+            if page.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if page.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else if page.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "right"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::RateLimited { wait_secs } => {
+                let wait = Duration::from_secs(*wait_secs);
+                write!(f, "not checked, wait {}m {}s", wait.as_secs() / 60, wait.as_secs() % 60)
+            }
+            Verdict::WrongLevel => write!(f, "not checked, the part is solved or still locked"),
+            Verdict::Unknown => write!(f, "not understood"),
+        }
+    }
+}
+
+/// One submitted answer and what came of it, a line of the ledger.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guess {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch, when known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub submitted_at: Option<u64>,
+}
+
+/// What the ledger says about submitting an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Submit,
+    /// The answer is already known to be right.
+    AlreadyRight,
+    /// The answer is already known to be wrong, for the reason given.
+    Refuse(String),
+}
+
+/// Every guess made so far, read from and appended to a JSON Lines file.
+#[derive(Debug, Clone, Default)]
+pub struct Ledger {
+    pub guesses: Vec<Guess>,
+}
+
+impl Ledger {
+    /// Read the ledger at `path`; a missing file is an empty ledger.
+    pub fn load(path: &Path) -> Result<Self, DynError> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(error) => return Err(error.into()),
+        };
+        let guesses = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                serde_json::from_str(line).map_err(|error| {
+                    ParseError::new(format!("{} line {}: {error}", path.display(), i + 1))
+                })
+            })
+            .collect::<Result<Vec<Guess>, ParseError>>()?;
+        Ok(Self { guesses })
+    }
+
+    /// Add `guess` to the ledger and to the end of the file at `path`.
+    pub fn record(&mut self, path: &Path, guess: Guess) -> Result<(), DynError> {
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{}", serde_json::to_string(&guess)?)?;
+        self.guesses.push(guess);
+        Ok(())
+    }
+
+    /// Whether a guess for one part of a puzzle has been accepted.
+    pub fn is_solved(&self, year: u16, day: u8, part: u8) -> bool {
+        self.guesses
+            .iter()
+            .any(|g| (g.year, g.day, g.part) == (year, day, part) && g.verdict == Verdict::Correct)
+    }
+
+    /// Whether `answer` is worth submitting for one part of a puzzle, given the earlier guesses.
+    pub fn check(&self, year: u16, day: u8, part: u8, answer: &str) -> Check {
+        let guesses: Vec<&Guess> =
+            self.guesses.iter().filter(|g| (g.year, g.day, g.part) == (year, day, part)).collect();
+        if let Some(right) = guesses.iter().find(|g| g.verdict == Verdict::Correct) {
+            return if right.answer == answer {
+                Check::AlreadyRight
+            } else {
+                Check::Refuse(format!("the right answer is already known to be {}", right.answer))
+            };
+        }
+        if let Some(same) = guesses.iter().find(|g| {
+            g.answer == answer
+                && matches!(g.verdict, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+        }) {
+            return Check::Refuse(format!("{answer} was already {}", same.verdict));
+        }
+
+        // Only whole numbers can be compared against the bounds.
+        let Ok(value) = answer.parse::<i128>() else {
+            return Check::Submit;
+        };
+        let bound = |verdict: Verdict| {
+            guesses
+                .iter()
+                .filter(move |g| g.verdict == verdict)
+                .filter_map(|g| g.answer.parse::<i128>().ok())
+        };
+        if let Some(high) = bound(Verdict::TooHigh).min().filter(|&high| value >= high) {
+            return Check::Refuse(format!("{answer} is not below {high}, which was too high"));
+        }
+        if let Some(low) = bound(Verdict::TooLow).max().filter(|&low| value <= low) {
+            return Check::Refuse(format!("{answer} is not above {low}, which was too low"));
+        }
+        Check::Submit
+    }
+}
+
+/// The JSON object written for a submission.
+#[derive(Debug, Serialize)]
+struct SubmitReport<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &'a str,
+    verdict: Verdict,
+}
+
+impl Submit {
+    /// The answer given on the command line, or else the one the day computes.
+    fn answer(&self) -> Result<String, DynError> {
+        if let Some(answer) = &self.answer {
+            return Ok(answer.trim().to_string());
+        }
+        let id = PuzzleId::new(self.year, self.day);
+        let run = Day::from_id(id)?.main()?;
+        let answer = if self.part == 1 { run.solution.part1 } else { run.solution.part2 };
+        answer
+            .map(|answer| answer.to_string())
+            .ok_or_else(|| format!("{id} gives no answer for part {}", self.part).into())
+    }
+
+    /// Check `answer` against `ledger`, then submit it with the client `connect` makes.
+    pub fn submit(
+        &self,
+        answer: &str,
+        ledger: &mut Ledger,
+        connect: impl FnOnce() -> Result<Client, DynError>,
+    ) -> Result<Verdict, DynError> {
+        match ledger.check(self.year, self.day, self.part, answer) {
+            Check::AlreadyRight => return Ok(Verdict::Correct),
+            Check::Refuse(reason) => return Err(format!("not submitting: {reason}").into()),
+            Check::Submit => {}
+        }
+        let page = connect()?.submit(self.year, self.day, self.part, answer)?;
+        let verdict = Verdict::parse(&page);
+        let submitted_at = SystemTime::now().duration_since(UNIX_EPOCH).ok().map(|t| t.as_secs());
+        let guess = Guess {
+            year: self.year,
+            day: self.day,
+            part: self.part,
+            answer: answer.to_string(),
+            verdict,
+            submitted_at,
+        };
+        ledger.record(&self.ledger, guess)?;
+        Ok(verdict)
+    }
+}
+
+impl TaskImpl for Submit {
+    fn main(&self, format: Format) -> Result<(), DynError> {
+        let answer = self.answer()?;
+        let mut ledger = Ledger::load(&self.ledger)?;
+        let verdict = self.submit(&answer, &mut ledger, || {
+            Ok(Client::new(base_url(self.base_url.as_deref()), session_token()?))
+        })?;
+
+        match format {
+            Format::Text => {
+                println!("{} day {} part {}: {answer} is {verdict}", self.year, self.day, self.part)
+            }
+            Format::Json => print_json(&SubmitReport {
+                year: self.year,
+                day: self.day,
+                part: self.part,
+                answer: &answer,
+                verdict,
+            })?,
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tasks::client::stand_in::serve;
+    use rstest::*;
+
+    fn guess(answer: &str, verdict: Verdict) -> Guess {
+        Guess {
+            year: 2024,
+            day: 9,
+            part: 2,
+            answer: answer.to_string(),
+            verdict,
+            submitted_at: None,
+        }
+    }
+
+    fn submit_command(ledger: &Path, base_url: &str) -> Submit {
+        Submit::parse_from([
+            "submit",
+            "--day",
+            "9",
+            "--part",
+            "2",
+            "--ledger",
+            ledger.to_str().unwrap(),
+            "--base-url",
+            base_url,
+        ])
+    }
+
+    #[rstest]
+    #[case("<p>That's the right answer!  You are one gold star closer.</p>", Verdict::Correct)]
+    #[case("<p>That's not the right answer; your answer is too high.</p>", Verdict::TooHigh)]
+    #[case("<p>That's not the right answer; your answer is too low.</p>", Verdict::TooLow)]
+    #[case("<p>That's not the right answer.  If you're stuck...</p>", Verdict::Wrong)]
+    #[case(
+        "<p>You gave an answer too recently. You have 4m 30s left to wait.</p>",
+        Verdict::RateLimited { wait_secs: 270 }
+    )]
+    #[case(
+        "<p>You gave an answer too recently. You have 35s left to wait.</p>",
+        Verdict::RateLimited { wait_secs: 35 }
+    )]
+    #[case("<p>You don't seem to be solving the right level.</p>", Verdict::WrongLevel)]
+    #[case("<html>Service unavailable</html>", Verdict::Unknown)]
+    fn test_parse_verdict(#[case] page: &str, #[case] expected: Verdict) {
+        assert_eq!(Verdict::parse(page), expected);
+    }
+
+    #[rstest]
+    #[case("6286183402063", Check::Refuse("6286183402063 was already too high".to_string()))]
+    #[case("7000000000000", Check::Refuse("7000000000000 is not below 6286183402063, which was too high".to_string()))]
+    #[case("100", Check::Refuse("100 was already too low".to_string()))]
+    #[case("99", Check::Refuse("99 is not above 100, which was too low".to_string()))]
+    #[case("5", Check::Refuse("5 is not above 100, which was too low".to_string()))]
+    #[case("6286183402062", Check::Submit)]
+    #[case("abc", Check::Submit)]
+    fn test_check_bounds(#[case] answer: &str, #[case] expected: Check) {
+        let ledger = Ledger {
+            guesses: vec![
+                guess("6286183402063", Verdict::TooHigh),
+                guess("100", Verdict::TooLow),
+                guess("50", Verdict::RateLimited { wait_secs: 60 }),
+            ],
+        };
+        assert_eq!(ledger.check(2024, 9, 2, answer), expected);
+        assert_eq!(ledger.check(2024, 9, 1, answer), Check::Submit);
+    }
+
+    #[test]
+    fn test_check_known_answer() {
+        let ledger = Ledger { guesses: vec![guess("42", Verdict::Correct)] };
+        assert_eq!(ledger.check(2024, 9, 2, "42"), Check::AlreadyRight);
+        assert!(matches!(ledger.check(2024, 9, 2, "43"), Check::Refuse(_)));
+        assert!(ledger.is_solved(2024, 9, 2));
+        assert!(!ledger.is_solved(2024, 9, 1));
+    }
+
+    #[test]
+    fn test_submit_records_guesses() -> Result<(), Box<dyn std::error::Error>> {
+        let (url, requests) =
+            serve(200, "<p>That's not the right answer; your answer is too low.</p>");
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("guesses.jsonl");
+        let command = submit_command(&path, &url);
+        let connect = || Ok(Client::new(url.clone(), "secret"));
+
+        let mut ledger = Ledger::load(&path)?;
+        assert_eq!(command.submit("1234", &mut ledger, connect)?, Verdict::TooLow);
+        // The bound now rules out anything lower without asking the server again.
+        assert!(command.submit("1000", &mut ledger, connect).is_err());
+
+        let received = requests.lock().unwrap().clone();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].url, "/2024/day/9/answer");
+        assert_eq!(received[0].cookie, "session=secret");
+        assert_eq!(received[0].body, "level=2&answer=1234");
+
+        let reloaded = Ledger::load(&path)?;
+        assert_eq!(reloaded.guesses.len(), 1);
+        assert_eq!(
+            (reloaded.guesses[0].answer.as_str(), reloaded.guesses[0].verdict),
+            ("1234", Verdict::TooLow)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_ledger_reports_bad_lines() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("guesses.jsonl");
+        fs::write(&path, "\n{\"year\":2024}\n")?;
+        let error = Ledger::load(&path).unwrap_err();
+        assert!(error.to_string().contains("line 2"), "{error}");
+        Ok(())
+    }
+}
@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+use std::fs;
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+
+use clap::Parser;
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_till1},
+    character::complete::{not_line_ending, space1},
+    combinator::{map, map_res, value},
+    sequence::tuple,
+    IResult,
+};
+use serde::Serialize;
+
+use super::{parallel_map, run_guarded, TaskImpl};
+use crate::commands::{Day, DynError, PuzzleId, Run};
+use crate::output::{print_json, Format};
+use crate::utils::ParseError;
+
+/// Check every answer in an expected-answers manifest and fail on any mismatch
+#[derive(Parser, Debug)]
+pub struct Verify {
+    /// Manifest with one `<year>/day<N> <part> <input> <expected>` entry per line
+    #[clap(long, short, default_value = "aoc/answers.txt")]
+    manifest: PathBuf,
+    /// Run up to this many days at once [default: one per CPU]
+    #[clap(long, short)]
+    jobs: Option<NonZeroUsize>,
+}
+
+/// Which half of a puzzle an expected answer belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+/// One line of the manifest: running `day` on `input` should give `expected` for `part`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expectation {
+    pub day: PuzzleId,
+    pub part: Part,
+    pub input: PathBuf,
+    pub expected: String,
+}
+
+fn parse_part(input: &str) -> IResult<&str, Part> {
+    alt((value(Part::One, tag("1")), value(Part::Two, tag("2"))))(input)
+}
+
+fn parse_expectation(input: &str) -> IResult<&str, Expectation> {
+    map(
+        tuple((
+            map_res(take_till1(|c: char| c.is_whitespace()), str::parse),
+            space1,
+            parse_part,
+            space1,
+            take_till1(|c: char| c.is_whitespace()),
+            space1,
+            not_line_ending,
+        )),
+        |(day, _, part, _, path, _, expected): (PuzzleId, _, Part, _, &str, _, &str)| Expectation {
+            day,
+            part,
+            input: PathBuf::from(path),
+            expected: expected.trim_end().to_string(),
+        },
+    )(input)
+}
+
+/// Parse a manifest, skipping blank lines and `#` comments.
+pub fn parse_manifest(manifest: &str) -> Result<Vec<Expectation>, ParseError> {
+    manifest
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .map(|(i, line)| match parse_expectation(line.trim()) {
+            Ok(("", expectation)) => Ok(expectation),
+            _ => Err(ParseError::new(format!("manifest line {}: {:?}", i + 1, line))),
+        })
+        .collect()
+}
+
+/// What a day produced for one expectation.
+fn observed(run: &Result<Run, String>, part: Part) -> Result<Option<String>, String> {
+    let run = run.as_ref().map_err(|e| e.clone())?;
+    let answer = match part {
+        Part::One => &run.solution.part1,
+        Part::Two => &run.solution.part2,
+    };
+    Ok(answer.as_ref().map(|a| a.to_string()))
+}
+
+impl Part {
+    fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+/// The JSON object written for one checked expectation.
+#[derive(Debug, Serialize)]
+struct CheckReport<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    input: String,
+    expected: &'a str,
+    observed: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<&'a str>,
+    ok: bool,
+}
+
+fn print_check(expectation: &Expectation, observed: &Result<Option<String>, String>) {
+    let label = format!(
+        "{} part {} ({})",
+        expectation.day,
+        expectation.part.number(),
+        expectation.input.display()
+    );
+    match observed {
+        Ok(Some(answer)) if *answer == expectation.expected => println!("ok   {label}"),
+        Ok(answer) => {
+            println!("FAIL {label}");
+            println!("  - {}", expectation.expected);
+            println!("  + {}", answer.as_deref().unwrap_or("<no answer>"));
+        }
+        Err(error) => println!("FAIL {label}\n  {error}"),
+    }
+}
+
+impl TaskImpl for Verify {
+    fn main(&self, format: Format) -> Result<(), DynError> {
+        let manifest = fs::read_to_string(&self.manifest)?;
+        let expectations = parse_manifest(&manifest)?;
+
+        // Several parts usually share a day and input, so each pair is only run once.
+        let mut pairs: Vec<(PuzzleId, PathBuf)> = Vec::new();
+        for expectation in expectations.iter() {
+            let pair = (expectation.day, expectation.input.clone());
+            if Day::ids().contains(&pair.0) && !pairs.contains(&pair) {
+                pairs.push(pair);
+            }
+        }
+        let results = parallel_map(&pairs, self.jobs, |(day, input)| run_guarded(*day, input));
+        let runs: HashMap<(PuzzleId, PathBuf), Result<Run, String>> =
+            pairs.into_iter().zip(results).collect();
+
+        let mut failures = 0;
+        for expectation in expectations.iter() {
+            let pair = (expectation.day, expectation.input.clone());
+            let observed = if let Some(run) = runs.get(&pair) {
+                observed(run, expectation.part)
+            } else {
+                Err("unknown day".to_string())
+            };
+            let ok = matches!(&observed, Ok(Some(answer)) if *answer == expectation.expected);
+            if !ok {
+                failures += 1;
+            }
+
+            match format {
+                Format::Text => print_check(expectation, &observed),
+                Format::Json => print_json(&CheckReport {
+                    year: expectation.day.year,
+                    day: expectation.day.day,
+                    part: expectation.part.number(),
+                    input: expectation.input.display().to_string(),
+                    expected: &expectation.expected,
+                    observed: observed.as_ref().ok().and_then(|a| a.as_deref()),
+                    error: observed.as_ref().err().map(String::as_str),
+                    ok,
+                })?,
+            }
+        }
+
+        if format == Format::Text {
+            println!("\n{} passed, {} failed", expectations.len() - failures, failures);
+        }
+        if failures > 0 {
+            return Err(
+                format!("{failures} of {} answers did not match", expectations.len()).into()
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_manifest() -> Result<(), Box<dyn std::error::Error>> {
+        let manifest = "# day part input expected\n\n2022/day1 2 aoc/inputs/2022/day1.txt 31\n2024/17  1  in.txt  4,6,3,5\n";
+        let observed = parse_manifest(manifest)?;
+        assert_eq!(
+            observed,
+            vec![
+                Expectation {
+                    day: PuzzleId::new(2022, 1),
+                    part: Part::Two,
+                    input: PathBuf::from("aoc/inputs/2022/day1.txt"),
+                    expected: "31".to_string(),
+                },
+                Expectation {
+                    day: PuzzleId::new(2024, 17),
+                    part: Part::One,
+                    input: PathBuf::from("in.txt"),
+                    expected: "4,6,3,5".to_string(),
+                },
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_manifest_rejects_bad_part() {
+        assert!(parse_manifest("2024/day1 3 in.txt 31").is_err());
+        assert!(parse_manifest("2024/day1 1 in.txt").is_err());
+        assert!(parse_manifest("day1 1 in.txt 31").is_err());
+    }
+}
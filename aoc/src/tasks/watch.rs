@@ -0,0 +1,233 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use clap::Parser;
+use serde::Serialize;
+
+use super::{guarded, TaskImpl};
+use crate::commands::{read_input, Answer, CommandImpl, Day, DynError, Solution};
+use crate::output::{print_json, Format};
+
+/// Re-run a day whenever its input file, or any file in its input directory, changes
+#[derive(Parser, Debug)]
+pub struct Watch {
+    /// How often to look for changes, in milliseconds
+    #[clap(long, default_value_t = 200)]
+    interval: u64,
+    /// The day to run, with its options; `--input` may name a directory of examples
+    #[clap(subcommand)]
+    day: Day,
+}
+
+/// What tells one version of a file from the next without reading it.
+type Stamp = (SystemTime, u64);
+
+/// The files a watched path stands for: the path itself, or the files directly inside it.
+fn files(path: &Path) -> Result<Vec<PathBuf>, DynError> {
+    if !path.is_dir() {
+        return Ok(vec![path.to_path_buf()]);
+    }
+    let mut files = Vec::new();
+    for entry in fs::read_dir(path)? {
+        let file = entry?.path();
+        if file.is_file() {
+            files.push(file);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn stamp(path: &Path) -> Option<Stamp> {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+/// The day's answers for one input, or why it has none.
+type Outcome = Result<Solution, String>;
+
+/// One run of the day against an input that changed, with what the input gave before.
+#[derive(Debug)]
+pub struct Change {
+    pub path: PathBuf,
+    pub now: Outcome,
+    pub before: Option<Outcome>,
+}
+
+/// The JSON object written for each re-run.
+#[derive(Debug, Serialize)]
+struct ChangeReport<'a> {
+    path: String,
+    year: u16,
+    day: u8,
+    part1: Option<&'a Answer>,
+    part2: Option<&'a Answer>,
+    was_part1: Option<&'a Answer>,
+    was_part2: Option<&'a Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<&'a str>,
+}
+
+/// Runs a day again for each input that has changed since it last looked.
+pub struct Watcher<'a> {
+    day: &'a Day,
+    path: PathBuf,
+    seen: BTreeMap<PathBuf, Stamp>,
+    outcomes: BTreeMap<PathBuf, Outcome>,
+}
+
+impl<'a> Watcher<'a> {
+    pub fn new(day: &'a Day) -> Result<Self, DynError> {
+        let path = day.input_path();
+        if path == Path::new("-") {
+            return Err("watch needs an input file or directory, not stdin".into());
+        }
+        Ok(Self { day, path, seen: BTreeMap::new(), outcomes: BTreeMap::new() })
+    }
+
+    /// Run the day against every input that is new or has changed since the last call, in path
+    /// order. Inputs that have gone are forgotten.
+    pub fn poll(&mut self) -> Result<Vec<Change>, DynError> {
+        let mut current = BTreeMap::new();
+        for file in files(&self.path)? {
+            if let Some(stamp) = stamp(&file) {
+                current.insert(file, stamp);
+            }
+        }
+        self.outcomes.retain(|file, _| current.contains_key(file));
+
+        let mut changes = Vec::new();
+        for (file, stamp) in current.iter() {
+            if self.seen.get(file) == Some(stamp) {
+                continue;
+            }
+            let now = guarded(|| Ok(self.day.run(&read_input(file)?)?.solution));
+            let before = self.outcomes.insert(file.clone(), now.clone());
+            changes.push(Change { path: file.clone(), now, before });
+        }
+        self.seen = current;
+        Ok(changes)
+    }
+}
+
+/// `answer`, and what it was before when there is a previous answer to compare with.
+fn compare(answer: &Answer, before: Option<&Answer>) -> String {
+    match before {
+        Some(before) if before == answer => format!("{answer} (unchanged)"),
+        Some(before) => format!("{answer} (was {before})"),
+        None => answer.to_string(),
+    }
+}
+
+fn print_change(change: &Change) {
+    let before = change.before.as_ref().and_then(|before| before.as_ref().ok());
+    let text = match &change.now {
+        Err(e) => format!("error: {e}"),
+        Ok(solution) if solution.is_empty() => "no answer".to_string(),
+        Ok(solution) => {
+            let parts = [
+                ("part 1", &solution.part1, before.and_then(|b| b.part1.as_ref())),
+                ("part 2", &solution.part2, before.and_then(|b| b.part2.as_ref())),
+            ];
+            let answers: Vec<String> = parts
+                .iter()
+                .filter_map(|(name, answer, before)| {
+                    answer.as_ref().map(|a| format!("{name}: {}", compare(a, *before)))
+                })
+                .collect();
+            answers.join(", ")
+        }
+    };
+    println!("{}: {text}", change.path.display());
+}
+
+impl TaskImpl for Watch {
+    fn main(&self, format: Format) -> Result<(), DynError> {
+        let mut watcher = Watcher::new(&self.day)?;
+        let id = self.day.id();
+        loop {
+            for change in watcher.poll()? {
+                match format {
+                    Format::Text => print_change(&change),
+                    Format::Json => {
+                        let solution = change.now.as_ref().ok();
+                        let before = change.before.as_ref().and_then(|b| b.as_ref().ok());
+                        print_json(&ChangeReport {
+                            path: change.path.display().to_string(),
+                            year: id.year,
+                            day: id.day,
+                            part1: solution.and_then(|s| s.part1.as_ref()),
+                            part2: solution.and_then(|s| s.part2.as_ref()),
+                            was_part1: before.and_then(|b| b.part1.as_ref()),
+                            was_part2: before.and_then(|b| b.part2.as_ref()),
+                            error: change.now.as_ref().err().map(String::as_str),
+                        })?
+                    }
+                }
+            }
+            thread::sleep(Duration::from_millis(self.interval));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::PuzzleId;
+
+    const MAZE: &str = "#####\n#S.E#\n#####\n";
+    const LONGER_MAZE: &str = "######\n#S..E#\n######\n";
+
+    fn part1(outcome: &Outcome) -> Option<Answer> {
+        outcome.as_ref().ok().and_then(|solution| solution.part1.clone())
+    }
+
+    #[test]
+    fn test_poll_directory() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = tempfile::tempdir()?;
+        fs::write(dir.path().join("a.txt"), MAZE)?;
+        fs::write(dir.path().join("b.txt"), "not a maze")?;
+        let day = Day::with_input(PuzzleId::new(2024, 16), dir.path())?;
+        let mut watcher = Watcher::new(&day)?;
+
+        let changes = watcher.poll()?;
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].path, dir.path().join("a.txt"));
+        assert_eq!(part1(&changes[0].now), Some(Answer::Number(2)));
+        assert!(changes[0].before.is_none());
+        assert!(changes[1].now.is_err());
+        assert!(watcher.poll()?.is_empty());
+
+        fs::write(dir.path().join("a.txt"), LONGER_MAZE)?;
+        fs::remove_file(dir.path().join("b.txt"))?;
+        let changes = watcher.poll()?;
+        assert_eq!(changes.len(), 1);
+        assert_eq!(part1(&changes[0].now), Some(Answer::Number(3)));
+        assert_eq!(part1(changes[0].before.as_ref().unwrap()), Some(Answer::Number(2)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_poll_file() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = tempfile::tempdir()?;
+        let input = dir.path().join("example.txt");
+        let day = Day::with_input(PuzzleId::new(2024, 16), &input)?;
+        let mut watcher = Watcher::new(&day)?;
+        assert!(watcher.poll()?.is_empty());
+        fs::write(&input, MAZE)?;
+        assert_eq!(watcher.poll()?.len(), 1);
+        assert!(Watcher::new(&Day::with_input(PuzzleId::new(2024, 16), Path::new("-"))?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_compare() {
+        let (two, three) = (Answer::Number(2), Answer::Number(3));
+        assert_eq!(compare(&three, None), "3");
+        assert_eq!(compare(&three, Some(&two)), "3 (was 2)");
+        assert_eq!(compare(&three, Some(&three)), "3 (unchanged)");
+    }
+}
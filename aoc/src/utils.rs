@@ -1,21 +1,44 @@
+pub mod geometry;
+pub mod grid;
+pub mod random;
+pub mod search;
+
 use std::{
     error::Error,
     fmt::{self, Debug},
     fs::File,
-    io::{BufRead, BufReader},
-    path::Path,
+    io::{self, BufRead, BufReader},
+    path::{Path, PathBuf},
     str::FromStr,
 };
 
+use nom::branch::alt;
+use nom::character::complete::line_ending;
+use nom::combinator::{cut, eof, not};
+use nom::IResult;
+
+/// A line of input that could not be converted, with its 1-based line and column.
 #[derive(Debug, Clone)]
 pub struct SlurpError {
     line: usize,
+    column: usize,
     msg: String,
 }
 
+impl SlurpError {
+    pub fn new(line: usize, column: usize, msg: String) -> Self {
+        Self { line, column, msg }
+    }
+
+    /// The error for the line at 0-based `index`, pointing at the start of the line.
+    fn at_index(index: usize, msg: String) -> Self {
+        Self::new(index + 1, 1, msg)
+    }
+}
+
 impl fmt::Display for SlurpError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Error at line {}: {}", self.line, self.msg)
+        write!(f, "parse error at line {}, column {}: {}", self.line, self.column, self.msg)
     }
 }
 
@@ -23,21 +46,37 @@ impl Error for SlurpError {}
 
 /// Slurp file will try to parse the string into `T` as long as T implements FromStr
 #[allow(clippy::missing_errors_doc)]
-pub fn slurp_file<P, T>(path: P) -> Result<Vec<T>, SlurpError>
+pub fn slurp_file<P, T>(path: P) -> Result<Vec<T>, AocError>
 where
     P: AsRef<Path>,
     T: FromStr,
     <T as FromStr>::Err: Error,
 {
-    let reader = File::open(&path).map(BufReader::new).expect("Failed to open file");
+    let reader = File::open(&path)
+        .map(BufReader::new)
+        .map_err(|source| AocError::Io { path: path.as_ref().to_path_buf(), source })?;
     let mut result = vec![];
     for (i, line) in reader.lines().enumerate() {
-        let line = line.map_err(|e| SlurpError { line: i, msg: e.to_string() })?;
-        result.push(line.parse::<T>().map_err(|e| SlurpError { line: i, msg: e.to_string() })?);
+        let line = line.map_err(|e| SlurpError::at_index(i, e.to_string()))?;
+        result.push(line.parse::<T>().map_err(|e| SlurpError::at_index(i, e.to_string()))?);
     }
     Ok(result)
 }
 
+/// Slurp str will try to parse each line of the string into `T` as long as T implements FromStr
+#[allow(clippy::missing_errors_doc)]
+pub fn slurp_str<T>(input: &str) -> Result<Vec<T>, SlurpError>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Error,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| line.parse::<T>().map_err(|e| SlurpError::at_index(i, e.to_string())))
+        .collect()
+}
+
 #[derive(Debug, Clone)]
 pub struct ParseError {
     msg: String,
@@ -52,6 +91,207 @@ impl Error for ParseError {}
 
 impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Error for command: {}", self.msg)
+        write!(f, "{}", self.msg)
+    }
+}
+
+/// Every way a day can fail, so errors read the same whichever day raised them.
+#[derive(Debug)]
+pub enum AocError {
+    /// The input could not be read.
+    Io { path: PathBuf, source: io::Error },
+    /// A line could not be converted by `slurp_file` or `slurp_str`.
+    Slurp(SlurpError),
+    /// The input did not have the shape a day expected.
+    Parse(ParseError),
+    /// A nom parser failed at a known position in the input.
+    Syntax { line: usize, column: usize, snippet: String, kind: String },
+    /// The input parsed but has no answer.
+    NoSolution(String),
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Io { source, .. } => Some(source),
+            AocError::Slurp(error) => Some(error),
+            AocError::Parse(error) => Some(error),
+            AocError::Syntax { .. } | AocError::NoSolution(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Io { path, source } => {
+                write!(f, "unable to read {}: {source}", path.display())
+            }
+            AocError::Slurp(error) => write!(f, "{error}"),
+            AocError::Parse(error) => write!(f, "{error}"),
+            AocError::Syntax { line, column, snippet, kind } => {
+                let gutter = " ".repeat(line.to_string().len());
+                write!(f, "parse error at line {line}, column {column}: expected {kind}")?;
+                write!(f, "\n{gutter} |\n{line} | {snippet}\n{gutter} | {:>column$}", "^")
+            }
+            AocError::NoSolution(msg) => write!(f, "no solution: {msg}"),
+        }
+    }
+}
+
+impl From<SlurpError> for AocError {
+    fn from(error: SlurpError) -> Self {
+        AocError::Slurp(error)
+    }
+}
+
+impl From<ParseError> for AocError {
+    fn from(error: ParseError) -> Self {
+        AocError::Parse(error)
+    }
+}
+
+impl AocError {
+    /// Locate a nom failure in `input`, the full text the parser was given.
+    pub fn from_nom(input: &str, error: nom::Err<nom::error::Error<&str>>) -> Self {
+        match error {
+            nom::Err::Error(e) | nom::Err::Failure(e) => {
+                Self::syntax(input, e.input, format!("{:?}", e.code))
+            }
+            nom::Err::Incomplete(_) => Self::syntax(input, "", "more input".to_string()),
+        }
+    }
+
+    /// A syntax error at the start of `part`, which has to be a slice of `input`.
+    pub fn at(input: &str, part: &str, kind: &str) -> Self {
+        let offset = part.as_ptr() as usize - input.as_ptr() as usize;
+        Self::syntax(input, &input[offset..], kind.to_string())
+    }
+
+    /// A syntax error at the start of `remaining`, the unparsed tail of `input`.
+    pub(crate) fn syntax(input: &str, remaining: &str, kind: String) -> Self {
+        // nom hands back the unparsed tail of `input`, so its length gives the offset.
+        let offset = input.len().saturating_sub(remaining.len());
+        let consumed = &input[..offset];
+        let line = consumed.matches('\n').count() + 1;
+        let line_start = consumed.rfind('\n').map_or(0, |i| i + 1);
+        let column = input[line_start..offset].chars().count() + 1;
+        let snippet = input[line_start..].lines().next().unwrap_or_default().to_string();
+        AocError::Syntax { line, column, snippet, kind }
+    }
+}
+
+/// Run `parser` on a line that is not blank.
+///
+/// At a blank line or the end of the input it fails softly, so that a list of lines stops there.
+/// Within a line any failure is fatal, so that it is reported where it happened rather than as
+/// input left over after the lines before it.
+pub fn line<'a, O>(
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O> {
+    move |input: &'a str| {
+        let (input, _) = not(alt((line_ending, eof)))(input)?;
+        cut(&mut parser)(input)
+    }
+}
+
+/// Unwrap the result of running a nom parser over the whole of `input`.
+///
+/// Anything but whitespace left over is an error, since list parsers stop quietly at the
+/// first line they cannot read.
+#[allow(clippy::missing_errors_doc)]
+pub fn from_nom<'a, O>(input: &'a str, result: IResult<&'a str, O>) -> Result<O, AocError> {
+    match result {
+        Ok((remaining, parsed)) if remaining.trim().is_empty() => Ok(parsed),
+        Ok((remaining, _)) => {
+            Err(AocError::syntax(input, remaining.trim_start(), "end of input".to_string()))
+        }
+        Err(error) => Err(AocError::from_nom(input, error)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use nom::character::complete::{digit1, line_ending};
+    use nom::multi::separated_list1;
+
+    fn parse_numbers(input: &str) -> IResult<&str, Vec<&str>> {
+        separated_list1(line_ending, digit1)(input)
+    }
+
+    #[test]
+    fn test_from_nom_locates_failure() {
+        let input = "12\n34\nx5\n";
+        let result = nom::sequence::terminated(parse_numbers, nom::combinator::eof)(input);
+        let Err(AocError::Syntax { line, column, snippet, kind }) = from_nom(input, result) else {
+            panic!("expected a syntax error");
+        };
+        assert_eq!((line, column), (2, 3));
+        assert_eq!(snippet, "34");
+        assert_eq!(kind, "Eof");
+    }
+
+    #[test]
+    fn test_from_nom_display() {
+        let input = "12\nab";
+        let error = AocError::from_nom(
+            input,
+            nom::Err::Error(nom::error::Error::new(&input[4..], nom::error::ErrorKind::Digit)),
+        );
+        let expected = "parse error at line 2, column 2: expected Digit\n  |\n2 | ab\n  |  ^";
+        assert_eq!(error.to_string(), expected);
+    }
+
+    #[test]
+    fn test_from_nom_rejects_leftover_input() {
+        let input = "12\n34\nx5\n";
+        let Err(AocError::Syntax { line, column, snippet, .. }) =
+            from_nom(input, parse_numbers(input))
+        else {
+            panic!("expected a syntax error");
+        };
+        assert_eq!((line, column), (3, 1));
+        assert_eq!(snippet, "x5");
+    }
+
+    #[test]
+    fn test_slurp_str_error_position() {
+        let error = AocError::from(slurp_str::<u32>("12\n34\nx5\n").unwrap_err());
+        assert_eq!(
+            error.to_string(),
+            "parse error at line 3, column 1: invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn test_line_reports_failures_within_the_line() {
+        let input = "12\n3x\n";
+        let result = separated_list1(line_ending, line(digit_then_eol))(input);
+        let Err(AocError::Syntax { line: l, column: c, .. }) = from_nom(input, result) else {
+            panic!("expected a syntax error");
+        };
+        assert_eq!((l, c), (2, 2));
+        let input = "12\n34\n\n";
+        let result = separated_list1(line_ending, line(digit_then_eol))(input);
+        assert_eq!(from_nom(input, result).unwrap(), vec!["12", "34"]);
+    }
+
+    fn digit_then_eol(input: &str) -> IResult<&str, &str> {
+        nom::sequence::terminated(digit1, nom::combinator::peek(alt((line_ending, eof))))(input)
+    }
+
+    #[test]
+    fn test_at() {
+        let input = "ab\ncd";
+        let error = AocError::at(input, &input[4..], "letter");
+        assert!(error.to_string().starts_with("parse error at line 2, column 2: expected letter"));
+    }
+
+    #[test]
+    fn test_from_nom_ok() -> Result<(), Box<dyn std::error::Error>> {
+        let input = "12\n34\n\n";
+        assert_eq!(from_nom(input, parse_numbers(input))?, vec!["12", "34"]);
+        Ok(())
     }
 }
@@ -0,0 +1,227 @@
+use std::ops::{Add, Mul, Sub};
+
+use super::grid::Position;
+
+/// One of the four ways to move on a grid, with north towards the first row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Direction; 4] =
+        [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    /// Read one of `^`, `>`, `v` and `<`.
+    pub fn from_arrow(arrow: char) -> Option<Self> {
+        match arrow {
+            '^' => Some(Direction::North),
+            '>' => Some(Direction::East),
+            'v' => Some(Direction::South),
+            '<' => Some(Direction::West),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::North => '^',
+            Direction::East => '>',
+            Direction::South => 'v',
+            Direction::West => '<',
+        }
+    }
+
+    /// A quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    /// A quarter turn anticlockwise.
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    pub fn reverse(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    /// Whether moving this way changes the row rather than the column.
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::North | Direction::South)
+    }
+
+    /// The change in position from one step this way.
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::North => Point::new(-1, 0),
+            Direction::East => Point::new(0, 1),
+            Direction::South => Point::new(1, 0),
+            Direction::West => Point::new(0, -1),
+        }
+    }
+
+    /// The cell one step this way from `p`, or `None` past the edge of a grid of `dimensions`.
+    pub fn step(self, p: Position, dimensions: (usize, usize)) -> Option<Position> {
+        Point::from(p).step(self).position(dimensions)
+    }
+}
+
+/// A point on an unbounded grid, which unlike a [`Position`] may lie above or left of the origin.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Point {
+    pub row: isize,
+    pub column: isize,
+}
+
+impl Point {
+    pub fn new(row: isize, column: isize) -> Self {
+        Self { row, column }
+    }
+
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.offset()
+    }
+
+    /// The number of steps between two points when moving only along rows and columns.
+    pub fn manhattan(self, other: Self) -> usize {
+        self.row.abs_diff(other.row) + self.column.abs_diff(other.column)
+    }
+
+    /// Rotate a quarter turn clockwise about the origin.
+    pub fn rotate_right(self) -> Self {
+        Self::new(self.column, -self.row)
+    }
+
+    /// Rotate a quarter turn anticlockwise about the origin.
+    pub fn rotate_left(self) -> Self {
+        Self::new(-self.column, self.row)
+    }
+
+    /// This point as a cell of a grid of `dimensions`, or `None` if it lies outside.
+    pub fn position(self, (nrows, ncols): (usize, usize)) -> Option<Position> {
+        let row = usize::try_from(self.row).ok().filter(|&row| row < nrows)?;
+        let column = usize::try_from(self.column).ok().filter(|&column| column < ncols)?;
+        Some((row, column))
+    }
+}
+
+impl From<Position> for Point {
+    fn from((row, column): Position) -> Self {
+        Self::new(row as isize, column as isize)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Point {
+        Point::new(self.row + rhs.row, self.column + rhs.column)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Point {
+        Point::new(self.row - rhs.row, self.column - rhs.column)
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: isize) -> Point {
+        Point::new(self.row * rhs, self.column * rhs)
+    }
+}
+
+/// A cell of a grid together with the direction something standing on it is facing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Heading {
+    pub position: Position,
+    pub direction: Direction,
+}
+
+impl Heading {
+    pub fn new(position: Position, direction: Direction) -> Self {
+        Self { position, direction }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::new(self.position, self.direction.turn_right())
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::new(self.position, self.direction.turn_left())
+    }
+
+    /// One step forward, or `None` when that would leave a grid of `dimensions`.
+    pub fn step(self, dimensions: (usize, usize)) -> Option<Self> {
+        let position = self.direction.step(self.position, dimensions)?;
+        Some(Self::new(position, self.direction))
+    }
+}
+
+impl From<Heading> for Position {
+    fn from(heading: Heading) -> Self {
+        heading.position
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case(Direction::North, Direction::East)]
+    #[case(Direction::East, Direction::South)]
+    #[case(Direction::South, Direction::West)]
+    #[case(Direction::West, Direction::North)]
+    fn test_turning(#[case] direction: Direction, #[case] right: Direction) {
+        assert_eq!(direction.turn_right(), right);
+        assert_eq!(right.turn_left(), direction);
+        assert_eq!(direction.offset().rotate_right(), right.offset());
+        assert_eq!(right.offset().rotate_left(), direction.offset());
+        assert_eq!(Direction::from_arrow(direction.arrow()), Some(direction));
+    }
+
+    #[test]
+    fn test_checked_step() {
+        let dimensions = (2, 3);
+        assert_eq!(Direction::North.step((0, 1), dimensions), None);
+        assert_eq!(Direction::West.step((1, 0), dimensions), None);
+        assert_eq!(Direction::East.step((1, 2), dimensions), None);
+        assert_eq!(Direction::South.step((0, 2), dimensions), Some((1, 2)));
+
+        let heading = Heading::new((0, 0), Direction::East);
+        assert_eq!(heading.step(dimensions), Some(Heading::new((0, 1), Direction::East)));
+        assert_eq!(heading.turn_left().step(dimensions), None);
+    }
+
+    #[test]
+    fn test_point_arithmetic() {
+        let p = Point::new(2, -3);
+        assert_eq!(p.step(Direction::North), Point::new(1, -3));
+        assert_eq!(p + Direction::East.offset() * 4, Point::new(2, 1));
+        assert_eq!(p - p, Point::default());
+        assert_eq!(p.manhattan(Point::new(-1, 1)), 7);
+        assert_eq!(p.position((5, 5)), None);
+        assert_eq!(Point::from((4, 1)).position((5, 5)), Some((4, 1)));
+    }
+}
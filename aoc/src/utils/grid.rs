@@ -0,0 +1,272 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use super::geometry::Direction;
+use super::{AocError, ParseError};
+
+/// A cell of a [`Grid`], as `(row, column)` counted from the top left.
+pub type Position = (usize, usize);
+
+/// Offsets to all eight surrounding cells, clockwise from the one above.
+const SURROUNDING: [(isize, isize); 8] =
+    [(-1, 0), (-1, 1), (0, 1), (1, 1), (1, 0), (1, -1), (0, -1), (-1, -1)];
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    nrows: usize,
+    ncols: usize,
+}
+
+impl<T> Grid<T> {
+    /// Build a grid from its rows, which must all be the same length.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn new(rows: Vec<Vec<T>>) -> Result<Self, AocError> {
+        let nrows = rows.len();
+        let ncols = rows.first().map_or(0, Vec::len);
+        if let Some(row) = rows.iter().position(|row| row.len() != ncols) {
+            let msg = format!("row {row} has {} cells, expected {ncols}", rows[row].len());
+            return Err(ParseError::new(msg).into());
+        }
+        Ok(Self { cells: rows.into_iter().flatten().collect(), nrows, ncols })
+    }
+
+    /// Read a grid with one line of text per row, turning each character into a cell with `cell`.
+    ///
+    /// The grid ends at the first blank line. A character `cell` rejects, a row of a different
+    /// length than the first, or anything but whitespace after the grid is reported with its
+    /// line and column.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn parse(input: &str, cell: impl FnMut(char) -> Option<T>) -> Result<Self, AocError> {
+        let (grid, rest) = Self::parse_prefix(input, cell)?;
+        if !rest.trim().is_empty() {
+            return Err(AocError::syntax(input, rest.trim_start(), "end of input".to_string()));
+        }
+        Ok(grid)
+    }
+
+    /// Read a grid like [`Grid::parse`] from the start of `input`, and hand back what follows it,
+    /// for inputs that go on after the grid.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn parse_prefix(
+        input: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<(Self, &str), AocError> {
+        let mut cells = Vec::new();
+        let mut nrows = 0;
+        let mut ncols = 0;
+        let mut end = 0;
+        for line in input.lines().take_while(|line| !line.is_empty()) {
+            // `lines` hands out slices of `input`, so the pointers give the offset of each line.
+            let start = line.as_ptr() as usize - input.as_ptr() as usize;
+            let mut width = 0;
+            for (i, c) in line.char_indices() {
+                let value = cell(c).ok_or_else(|| {
+                    AocError::syntax(input, &input[start + i..], "grid cell".to_string())
+                })?;
+                cells.push(value);
+                width += 1;
+            }
+            if nrows == 0 {
+                ncols = width;
+            } else if width != ncols {
+                let kind = format!("a row of {ncols} cells");
+                return Err(AocError::syntax(input, &input[start..], kind));
+            }
+            nrows += 1;
+            end = start + line.len();
+        }
+        if nrows == 0 {
+            return Err(AocError::syntax(input, input, "grid cell".to_string()));
+        }
+        Ok((Self { cells, nrows, ncols }, &input[end..]))
+    }
+
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    /// The number of rows and columns.
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.nrows, self.ncols)
+    }
+
+    pub fn contains(&self, (row, column): Position) -> bool {
+        row < self.nrows && column < self.ncols
+    }
+
+    pub fn get(&self, p: Position) -> Option<&T> {
+        self.contains(p).then(|| &self.cells[p.0 * self.ncols + p.1])
+    }
+
+    pub fn get_mut(&mut self, p: Position) -> Option<&mut T> {
+        if self.contains(p) {
+            Some(&mut self.cells[p.0 * self.ncols + p.1])
+        } else {
+            None
+        }
+    }
+
+    /// The cell `drow` rows and `dcol` columns away from `p`, or `None` past an edge.
+    pub fn offset(&self, (row, column): Position, drow: isize, dcol: isize) -> Option<Position> {
+        let p = (row.checked_add_signed(drow)?, column.checked_add_signed(dcol)?);
+        self.contains(p).then_some(p)
+    }
+
+    /// The cells sharing an edge with `p`: above, right, below and left, skipping those off the grid.
+    pub fn neighbors4(&self, p: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL.iter().filter_map(move |&direction| direction.step(p, self.dimensions()))
+    }
+
+    /// The cells sharing an edge or a corner with `p`, clockwise from the one above.
+    pub fn neighbors8(&self, p: Position) -> impl Iterator<Item = Position> + '_ {
+        SURROUNDING.iter().filter_map(move |&(drow, dcol)| self.offset(p, drow, dcol))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let ncols = self.ncols;
+        (0..self.nrows * ncols).map(move |i| (i / ncols, i % ncols))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.ncols.max(1))
+    }
+
+    /// A grid of the same shape with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { cells: self.cells.iter().map(f).collect(), nrows: self.nrows, ncols: self.ncols }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A grid with every cell set to `value`.
+    pub fn filled(nrows: usize, ncols: usize, value: T) -> Self {
+        Self { cells: vec![value; nrows * ncols], nrows, ncols }
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// The first position holding `value`, row by row.
+    pub fn find(&self, value: &T) -> Option<Position> {
+        self.iter().find(|(_, cell)| *cell == value).map(|(p, _)| p)
+    }
+
+    /// Every position holding `value`, row by row.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Position> + 'a {
+        self.iter().filter(move |(_, cell)| *cell == value).map(|(p, _)| p)
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Position) -> &T {
+        let (nrows, ncols) = self.dimensions();
+        self.get(p).unwrap_or_else(|| panic!("{p:?} is outside a {nrows}x{ncols} grid"))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, p: Position) -> &mut T {
+        let (nrows, ncols) = self.dimensions();
+        self.get_mut(p).unwrap_or_else(|| panic!("{p:?} is outside a {nrows}x{ncols} grid"))
+    }
+}
+
+/// One line per row, with each cell written through its own `Display`.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>, AocError> {
+        Grid::parse(input, |c| c.to_digit(10))
+    }
+
+    #[test]
+    fn test_parse_and_display() -> Result<(), Box<dyn std::error::Error>> {
+        let grid = digits("123\n456\n\n")?;
+        assert_eq!(grid.dimensions(), (2, 3));
+        assert_eq!(grid[(1, 0)], 4);
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.to_string(), "123\n456");
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_prefix() -> Result<(), Box<dyn std::error::Error>> {
+        let (grid, rest) = Grid::parse_prefix("12\n34\n\nmore\n", |c| c.to_digit(10))?;
+        assert_eq!(grid.dimensions(), (2, 2));
+        assert_eq!(rest, "\n\nmore\n");
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("12\n3x\n", 2, 2, "grid cell")]
+    #[case("12\n345\n", 2, 1, "a row of 2 cells")]
+    #[case("12\n34\n\n56\n", 4, 1, "end of input")]
+    #[case("", 1, 1, "grid cell")]
+    fn test_parse_errors(
+        #[case] input: &str,
+        #[case] line: usize,
+        #[case] column: usize,
+        #[case] kind: &str,
+    ) {
+        match digits(input) {
+            Err(AocError::Syntax { line: l, column: c, kind: k, .. }) => {
+                assert_eq!((l, c, k.as_str()), (line, column, kind));
+            }
+            other => panic!("expected a syntax error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_new_rejects_ragged_rows() {
+        assert!(Grid::new(vec![vec![1, 2], vec![3]]).is_err());
+        assert!(Grid::new(vec![vec![1, 2], vec![3, 4]]).is_ok());
+    }
+
+    #[test]
+    fn test_neighbors() -> Result<(), Box<dyn std::error::Error>> {
+        let grid = digits("123\n456\n789")?;
+        assert_eq!(grid.neighbors4((0, 0)).collect::<Vec<_>>(), vec![(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbors4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbors8((0, 2)).collect::<Vec<_>>(), vec![(1, 2), (1, 1), (0, 1)]);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+        Ok(())
+    }
+
+    #[test]
+    fn test_find() -> Result<(), Box<dyn std::error::Error>> {
+        let grid = digits("101\n010")?;
+        assert_eq!(grid.find(&0), Some((0, 1)));
+        assert_eq!(grid.find_all(&1).collect::<Vec<_>>(), vec![(0, 0), (0, 2), (1, 1)]);
+        assert_eq!(grid.find(&7), None);
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use super::grid::{Grid, Position};
+use super::search::{bfs, Neighbors};
+
+/// The random number generator behind every generated input. ChaCha gives the same stream for a
+/// seed on every platform and `rand` release, so a seed names one input for good.
+pub type InputRng = ChaCha8Rng;
+
+pub fn seeded(seed: u64) -> InputRng {
+    InputRng::seed_from_u64(seed)
+}
+
+/// A maze with exactly one path between any two open squares, carved by a randomised
+/// depth-first search. Open squares are `true`.
+///
+/// Squares at odd rows and columns are always open and the border is always wall, so both
+/// dimensions should be odd; an even one leaves a second wall along that edge.
+pub fn perfect_maze(rng: &mut InputRng, nrows: usize, ncols: usize) -> Grid<bool> {
+    let mut open = Grid::filled(nrows, ncols, false);
+    if nrows < 3 || ncols < 3 {
+        return open;
+    }
+    open[(1, 1)] = true;
+    let mut stack: Vec<Position> = vec![(1, 1)];
+    while let Some(&(row, column)) = stack.last() {
+        let mut unvisited: Vec<(Position, Position)> = [(0, 2), (2, 0), (0, -2), (-2, 0)]
+            .into_iter()
+            .filter_map(|(drow, dcol)| {
+                let next = open.offset((row, column), drow, dcol)?;
+                let inside = next.0 < nrows - 1 && next.1 < ncols - 1;
+                let wall = ((row + next.0) / 2, (column + next.1) / 2);
+                (inside && !open[next]).then_some((wall, next))
+            })
+            .collect();
+        unvisited.shuffle(rng);
+        match unvisited.pop() {
+            Some((wall, next)) => {
+                open[wall] = true;
+                open[next] = true;
+                stack.push(next);
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+    open
+}
+
+/// The open squares of a maze, moving between neighbours.
+struct Open<'a>(&'a Grid<bool>);
+
+impl Neighbors for Open<'_> {
+    type Node = Position;
+
+    fn neighbors(&self, p: &Position) -> impl IntoIterator<Item = Position> {
+        self.0.neighbors4(*p).filter(|&n| self.0[n])
+    }
+}
+
+/// The squares from `start` to the open square furthest from it, the longest corridor out of
+/// `start`.
+pub fn longest_corridor(maze: &Grid<bool>, start: Position) -> Vec<Position> {
+    let search = bfs(&Open(maze), [start]);
+    let furthest = search.distances().iter().max_by_key(|&(&p, &distance)| (distance, p));
+    furthest.and_then(|(end, _)| search.path_to(end)).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_perfect_maze_is_a_tree() {
+        let maze = perfect_maze(&mut seeded(7), 11, 15);
+        let squares: Vec<Position> =
+            maze.iter().filter(|(_, &open)| open).map(|(p, _)| p).collect();
+        assert!(squares
+            .iter()
+            .all(|&(row, column)| (1..10).contains(&row) && (1..14).contains(&column)));
+        // Every square is reached, and a tree has one corridor fewer than it has squares.
+        let reached = bfs(&Open(&maze), [(1, 1)]).distances().len();
+        assert_eq!(reached, squares.len());
+        let rooms = 5 * 7;
+        assert_eq!(squares.len(), rooms + rooms - 1);
+    }
+
+    #[test]
+    fn test_seed_fixes_the_maze() {
+        let maze = |seed| perfect_maze(&mut seeded(seed), 9, 9).to_string();
+        assert_eq!(maze(1), maze(1));
+        assert_ne!(maze(1), maze(2));
+    }
+
+    #[test]
+    fn test_longest_corridor() {
+        let maze = perfect_maze(&mut seeded(3), 9, 9);
+        let corridor = longest_corridor(&maze, (1, 1));
+        assert_eq!(corridor.first(), Some(&(1, 1)));
+        let furthest = bfs(&Open(&maze), [(1, 1)]).distances().values().copied().max();
+        assert_eq!(Some(corridor.len() - 1), furthest);
+    }
+}
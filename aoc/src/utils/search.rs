@@ -0,0 +1,277 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// A graph where every move costs the same, searched with [`bfs`].
+pub trait Neighbors {
+    type Node: Clone + Eq + Hash;
+
+    /// The nodes one move away from `node`.
+    fn neighbors(&self, node: &Self::Node) -> impl IntoIterator<Item = Self::Node>;
+}
+
+/// A graph where moves have a cost, searched with [`dijkstra`] or [`astar`].
+pub trait WeightedNeighbors {
+    type Node: Clone + Eq + Hash;
+
+    /// The nodes one move away from `node`, each with the cost of getting there. Costs must be
+    /// positive, or the shortest paths found could run in circles.
+    fn weighted_neighbors(
+        &self,
+        node: &Self::Node,
+    ) -> impl IntoIterator<Item = (Self::Node, usize)>;
+}
+
+/// What a search found: the distance from the nearest source to every node it reached, and
+/// every node that comes right before each one on some shortest path.
+#[derive(Debug, Clone)]
+pub struct Search<N> {
+    distances: HashMap<N, usize>,
+    predecessors: HashMap<N, Vec<N>>,
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    fn new(sources: impl IntoIterator<Item = N>) -> Self {
+        let distances: HashMap<N, usize> = sources.into_iter().map(|source| (source, 0)).collect();
+        Self { distances, predecessors: HashMap::new() }
+    }
+
+    /// Record reaching `node` from `from` in `distance`, returning whether that was an improvement.
+    fn relax(&mut self, from: &N, node: N, distance: usize) -> bool {
+        match self.distances.get(&node).copied() {
+            Some(known) if distance > known => false,
+            Some(known) if distance == known => {
+                self.predecessors.entry(node).or_default().push(from.clone());
+                false
+            }
+            _ => {
+                self.distances.insert(node.clone(), distance);
+                self.predecessors.insert(node, vec![from.clone()]);
+                true
+            }
+        }
+    }
+
+    /// The length of a shortest path from any source to `node`, if there is one.
+    pub fn distance(&self, node: &N) -> Option<usize> {
+        self.distances.get(node).copied()
+    }
+
+    /// Every node that was reached, with its distance.
+    pub fn distances(&self) -> &HashMap<N, usize> {
+        &self.distances
+    }
+
+    /// The nodes right before `node` on its shortest paths; empty for the sources.
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.predecessors.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    /// One shortest path from a source to `goal`, starting with the source.
+    pub fn path_to(&self, goal: &N) -> Option<Vec<N>> {
+        self.distances.get(goal)?;
+        let mut path = vec![goal.clone()];
+        while let Some(previous) = self.predecessors(path.last()?).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every node on any shortest path from a source to one of `goals`, the goals included.
+    pub fn on_shortest_paths(&self, goals: impl IntoIterator<Item = N>) -> HashSet<N> {
+        let mut seen: HashSet<N> = HashSet::new();
+        let mut stack: Vec<N> =
+            goals.into_iter().filter(|goal| self.distances.contains_key(goal)).collect();
+        while let Some(node) = stack.pop() {
+            if seen.insert(node.clone()) {
+                stack.extend(self.predecessors(&node).iter().cloned());
+            }
+        }
+        seen
+    }
+}
+
+/// Breadth-first search from every node in `sources` at once.
+pub fn bfs<G: Neighbors>(graph: &G, sources: impl IntoIterator<Item = G::Node>) -> Search<G::Node> {
+    let mut search = Search::new(sources);
+    let mut queue: VecDeque<(G::Node, usize)> =
+        search.distances.keys().map(|node| (node.clone(), 0)).collect();
+    while let Some((node, distance)) = queue.pop_front() {
+        for neighbor in graph.neighbors(&node) {
+            if search.relax(&node, neighbor.clone(), distance + 1) {
+                queue.push_back((neighbor, distance + 1));
+            }
+        }
+    }
+    search
+}
+
+/// A node waiting in a [`BinaryHeap`], popped cheapest first.
+struct Queued<N> {
+    priority: usize,
+    cost: usize,
+    node: N,
+}
+
+impl<N> PartialEq for Queued<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N> Eq for Queued<N> {}
+
+impl<N> PartialOrd for Queued<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> Ord for Queued<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // `BinaryHeap` is a max-heap, so the comparison is flipped.
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// Dijkstra's algorithm from every node in `sources` at once.
+pub fn dijkstra<G: WeightedNeighbors>(
+    graph: &G,
+    sources: impl IntoIterator<Item = G::Node>,
+) -> Search<G::Node> {
+    let mut search = Search::new(sources);
+    let mut heap: BinaryHeap<Queued<G::Node>> = search
+        .distances
+        .keys()
+        .map(|node| Queued { priority: 0, cost: 0, node: node.clone() })
+        .collect();
+    while let Some(Queued { cost, node, .. }) = heap.pop() {
+        if search.distance(&node).is_some_and(|known| cost > known) {
+            continue;
+        }
+        for (neighbor, step) in graph.weighted_neighbors(&node) {
+            let cost = cost + step;
+            if search.relax(&node, neighbor.clone(), cost) {
+                heap.push(Queued { priority: cost, cost, node: neighbor });
+            }
+        }
+    }
+    search
+}
+
+/// The cheapest path from `start` to any node `is_goal` accepts, with its cost.
+///
+/// `heuristic` must never overestimate the cost left to a goal, or the path found may not be
+/// the cheapest.
+pub fn astar<G: WeightedNeighbors>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl Fn(&G::Node) -> bool,
+    heuristic: impl Fn(&G::Node) -> usize,
+) -> Option<(Vec<G::Node>, usize)> {
+    let mut search = Search::new([start.clone()]);
+    let mut heap = BinaryHeap::from([Queued { priority: heuristic(&start), cost: 0, node: start }]);
+    while let Some(Queued { cost, node, .. }) = heap.pop() {
+        if search.distance(&node).is_some_and(|known| cost > known) {
+            continue;
+        }
+        if is_goal(&node) {
+            return Some((search.path_to(&node)?, cost));
+        }
+        for (neighbor, step) in graph.weighted_neighbors(&node) {
+            let cost = cost + step;
+            if search.relax(&node, neighbor.clone(), cost) {
+                let priority = cost + heuristic(&neighbor);
+                heap.push(Queued { priority, cost, node: neighbor });
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::geometry::Point;
+    use crate::utils::grid::{Grid, Position};
+
+    /// Open cells are digits giving the cost of stepping onto them; `#` is a wall.
+    struct Costs(Grid<Option<usize>>);
+
+    impl Costs {
+        fn parse(input: &str) -> Self {
+            let grid = Grid::parse(input, |c| match c {
+                '#' => Some(None),
+                _ => c.to_digit(10).map(|cost| Some(cost as usize)),
+            });
+            Costs(grid.unwrap())
+        }
+    }
+
+    impl Neighbors for Costs {
+        type Node = Position;
+
+        fn neighbors(&self, p: &Position) -> impl IntoIterator<Item = Position> {
+            self.0.neighbors4(*p).filter(|&n| self.0[n].is_some())
+        }
+    }
+
+    impl WeightedNeighbors for Costs {
+        type Node = Position;
+
+        fn weighted_neighbors(&self, p: &Position) -> impl IntoIterator<Item = (Position, usize)> {
+            self.0.neighbors4(*p).filter_map(|n| self.0[n].map(|cost| (n, cost)))
+        }
+    }
+
+    const MAZE: &str = "\
+1111
+1#91
+1111
+#1#1";
+
+    #[test]
+    fn test_bfs() {
+        let maze = Costs::parse(MAZE);
+        let search = bfs(&maze, [(0, 0)]);
+        assert_eq!(search.distance(&(3, 3)), Some(6));
+        assert_eq!(search.distance(&(1, 1)), None);
+        assert_eq!(search.path_to(&(3, 1)), Some(vec![(0, 0), (1, 0), (2, 0), (2, 1), (3, 1)]));
+        // Two ways round the wall are equally short.
+        assert_eq!(search.predecessors(&(2, 2)).len(), 2);
+        assert_eq!(search.on_shortest_paths([(2, 2)]).len(), 8);
+    }
+
+    #[test]
+    fn test_bfs_from_several_sources() {
+        let maze = Costs::parse(MAZE);
+        let search = bfs(&maze, [(0, 0), (3, 3)]);
+        assert_eq!(search.distance(&(2, 3)), Some(1));
+        assert_eq!(search.distance(&(2, 1)), Some(3));
+        assert_eq!(search.path_to(&(3, 3)), Some(vec![(3, 3)]));
+    }
+
+    #[test]
+    fn test_dijkstra_avoids_expensive_cells() {
+        let maze = Costs::parse(MAZE);
+        let search = dijkstra(&maze, [(0, 0)]);
+        assert_eq!(search.distance(&(1, 3)), Some(4));
+        assert_eq!(search.distance(&(1, 2)), Some(11));
+        let path = search.path_to(&(3, 3)).unwrap();
+        assert!(!path.contains(&(1, 2)));
+        assert_eq!(search.distance(&(3, 3)), Some(6));
+        assert_eq!(search.on_shortest_paths([(3, 3)]).len(), 11);
+    }
+
+    #[test]
+    fn test_astar_agrees_with_dijkstra() {
+        let maze = Costs::parse(MAZE);
+        let goal = (3, 1);
+        let manhattan = |p: &Position| Point::from(*p).manhattan(Point::from(goal));
+        let (path, cost) = astar(&maze, (0, 3), |p| *p == goal, manhattan).unwrap();
+        assert_eq!(Some(cost), dijkstra(&maze, [(0, 3)]).distance(&goal));
+        assert_eq!((path.first(), path.last()), (Some(&(0, 3)), Some(&goal)));
+        assert_eq!(astar(&maze, (0, 0), |p| *p == (1, 1), manhattan), None);
+    }
+}
@@ -0,0 +1,51 @@
+//! Every day can make up inputs of its own, which have to parse, solve, and come out the same
+//! for the same seed.
+
+use aoc::commands::{CommandImpl, Day, Parts, PuzzleId};
+use proptest::prelude::*;
+use proptest::sample::select;
+
+/// Small enough that solving every day stays quick in a debug build.
+const SIZE: Option<usize> = Some(9);
+
+#[test]
+fn test_every_day_has_a_generator() {
+    let missing: Vec<PuzzleId> = Day::ids()
+        .iter()
+        .copied()
+        .filter(|&id| Day::from_id(id).unwrap().generate_input(0, SIZE).is_none())
+        .collect();
+    assert!(missing.is_empty(), "no input generator for {missing:?}");
+}
+
+#[test]
+fn test_generated_inputs_solve() -> Result<(), Box<dyn std::error::Error>> {
+    for &id in Day::ids() {
+        let day = Day::from_id(id)?;
+        let input = day.generate_input(1, SIZE).unwrap();
+        let run = day.run_parts(&input, Parts::Both);
+        assert!(
+            run.is_ok(),
+            "{id} failed on its own input: {}\n{input}",
+            run.as_ref().unwrap_err()
+        );
+        // A day answers no part it does not declare, though one still being written may answer
+        // fewer.
+        let solution = run?.solution;
+        let parts = day.puzzle().parts;
+        assert!(solution.part1.is_none() || parts.part1(), "{id} answers part 1");
+        assert!(solution.part2.is_none() || parts.part2(), "{id} answers part 2");
+    }
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn test_generated_inputs_parse(id in select(Day::ids()), seed in any::<u64>()) {
+        let day = Day::from_id(id).unwrap();
+        let input = day.generate_input(seed, SIZE).unwrap();
+        let checked = day.check_input(&input);
+        prop_assert!(checked.is_ok(), "{id} rejected {input:?}: {}", checked.unwrap_err());
+        prop_assert_eq!(day.generate_input(seed, SIZE), Some(input));
+    }
+}
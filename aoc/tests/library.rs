@@ -0,0 +1,56 @@
+use std::collections::VecDeque;
+
+use aoc::commands::y2024::day13::Day13;
+use aoc::commands::y2024::day24::{self, Assignment, Initialization, LogicalStatement};
+use aoc::commands::y2024::day9;
+use aoc::commands::{Answer, CommandImpl, Day, Parts, PuzzleId, Solver};
+
+const DAY5_EXAMPLE: &str = "47|53\n97|13\n97|61\n97|47\n75|29\n61|13\n75|53\n29|13\n97|29\n\
+53|29\n61|53\n97|53\n61|29\n47|13\n75|47\n97|75\n47|61\n75|61\n47|29\n75|13\n53|13\n\n\
+75,47,61,53,29\n97,61,53,29,13\n75,29,13\n75,97,47,61,53\n61,13,29\n97,13,75,29,47\n";
+
+#[test]
+fn test_day_by_id() -> Result<(), Box<dyn std::error::Error>> {
+    let run = Day::from_id(PuzzleId::new(2024, 5))?.run(DAY5_EXAMPLE)?;
+    assert_eq!(run.solution.part1, Some(Answer::Number(143)));
+    assert_eq!(run.solution.part2, Some(Answer::Number(123)));
+    Ok(())
+}
+
+#[test]
+fn test_single_part() -> Result<(), Box<dyn std::error::Error>> {
+    let run = Day::from_id(PuzzleId::new(2024, 5))?.run_parts(DAY5_EXAMPLE, Parts::Two)?;
+    assert_eq!(run.solution.part1, None);
+    assert_eq!(run.solution.part2, Some(Answer::Number(123)));
+    Ok(())
+}
+
+#[test]
+fn test_solver_directly() -> Result<(), Box<dyn std::error::Error>> {
+    let input = "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\n";
+    let day = Day13::default();
+    let machines = day.parse(input)?;
+    let mut machine = machines[0].clone();
+    assert_eq!(machine.solve(), Some((80, 40)));
+    Ok(())
+}
+
+#[test]
+fn test_gate_evaluation() {
+    let wires: Vec<Initialization> = vec![("x00".to_string(), 1), ("y00".to_string(), 1)];
+    let assignments: VecDeque<Assignment> = VecDeque::from(vec![
+        (LogicalStatement::Xor("x00".to_string(), "y00".to_string()), "z00".to_string()),
+        (LogicalStatement::And("x00".to_string(), "y00".to_string()), "z01".to_string()),
+    ]);
+    let wires = day24::evaluate(&wires, &assignments);
+    assert_eq!(day24::build_z_binary(&wires), 0b10);
+}
+
+#[test]
+fn test_block_compaction() {
+    use day9::CheckSummable;
+
+    let disk_map: Vec<usize> = "2333133121414131402".bytes().map(|b| (b - b'0') as usize).collect();
+    let checksum: usize = day9::compact(&disk_map).iter().map(|block| block.checksum()).sum();
+    assert_eq!(checksum, 2858);
+}
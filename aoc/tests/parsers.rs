@@ -0,0 +1,261 @@
+//! Every day's parser has to accept well-formed input and turn anything else into an error,
+//! never a panic. Valid inputs are generated from a grammar per day, and near-valid ones by
+//! mutating those; proptest reports any panic as a failure and shrinks it to a small input.
+
+use std::collections::HashSet;
+
+use aoc::commands::y2024::day1::Day1;
+use aoc::commands::y2024::day11::Day11;
+use aoc::commands::y2024::day18::Day18;
+use aoc::commands::y2024::day19::Day19;
+use aoc::commands::y2024::day23::Day23;
+use aoc::commands::y2024::day7::Day7;
+use aoc::commands::y2024::day9::Day9;
+use aoc::commands::{CommandImpl, Day, PuzzleId, Solver};
+use proptest::collection::{hash_set, vec};
+use proptest::prelude::*;
+use proptest::sample::{select, Index};
+
+/// `line` repeated on a few lines, each ending in a newline.
+fn lines(line: &'static str) -> BoxedStrategy<String> {
+    vec(line, 1..8).prop_map(|lines| lines.join("\n") + "\n").boxed()
+}
+
+/// A rectangular grid whose cells are drawn from `cells`.
+fn grid(cells: &'static str) -> BoxedStrategy<String> {
+    let cells: Vec<char> = cells.chars().collect();
+    (1..8_usize, 1..8_usize)
+        .prop_flat_map(move |(nrows, ncols)| vec(vec(select(cells.clone()), ncols), nrows))
+        .prop_map(|rows| rows.iter().map(|row| row.iter().collect::<String>() + "\n").collect())
+        .boxed()
+}
+
+/// A grid drawn from `cells` with one each of `marks` written over distinct squares, such as the
+/// one robot of a warehouse or the start and end of a maze.
+fn grid_with(cells: &'static str, marks: &'static str) -> BoxedStrategy<String> {
+    (grid(cells), vec(any::<Index>(), marks.len()))
+        .prop_filter("room for every mark", move |(grid, _)| {
+            grid.chars().filter(|&c| c != '\n').count() >= marks.len()
+        })
+        .prop_map(move |(grid, indices)| {
+            let mut cells: Vec<char> = grid.chars().collect();
+            let mut squares: Vec<usize> = (0..cells.len()).filter(|&i| cells[i] != '\n').collect();
+            for (mark, index) in marks.chars().zip(indices) {
+                cells[squares.remove(index.index(squares.len()))] = mark;
+            }
+            cells.into_iter().collect()
+        })
+        .boxed()
+}
+
+/// Well-formed input for the day `id`.
+fn valid_input(id: PuzzleId) -> Option<BoxedStrategy<String>> {
+    let strategy = match (id.year, id.day) {
+        (2024, 1) => lines(r"[0-9]{1,5}   [0-9]{1,5}"),
+        (2022, 1) => vec(lines(r"[0-9]{1,5}"), 1..4).prop_map(|elves| elves.join("\n")).boxed(),
+        (2024, 2) => lines(r"[0-9]{1,2}( [0-9]{1,2}){0,6}"),
+        (2024, 3) => lines(r"(mul\([0-9]{1,3},[0-9]{1,3}\)|do\(\)|don't\(\)|[a-z ,()]){0,20}"),
+        (2024, 4) => grid("XMAS"),
+        (2024, 5) => (lines(r"[0-9]{1,2}\|[0-9]{1,2}"), lines(r"[0-9]{1,2}(,[0-9]{1,2}){0,6}"))
+            .prop_map(|(rules, updates)| format!("{rules}\n{updates}"))
+            .boxed(),
+        (2024, 6) => grid(".#^"),
+        (2024, 7) => lines(r"[0-9]{1,6}: [0-9]{1,3}( [0-9]{1,3}){0,5}"),
+        (2024, 9) => r"[0-9]{1,30}\n".boxed(),
+        (2024, 10) => grid("0123456789"),
+        (2024, 11) => r"[0-9]{1,6}( [0-9]{1,6}){0,7}\n".boxed(),
+        (2024, 12) => grid("ABCD"),
+        (2024, 13) => vec(
+            "Button A: X\\+[0-9]{1,3}, Y\\+[0-9]{1,3}\nButton B: X\\+[0-9]{1,3}, Y\\+[0-9]{1,3}\n\
+             Prize: X=[0-9]{1,5}, Y=[0-9]{1,5}\n",
+            1..4,
+        )
+        .prop_map(|machines| machines.join("\n"))
+        .boxed(),
+        (2024, 14) => lines(r"p=[0-9]{1,3},[0-9]{1,3} v=-?[0-9]{1,2},-?[0-9]{1,2}"),
+        (2024, 15) => (grid_with("#.O", "@"), lines(r"[<>^v]{1,20}"))
+            .prop_map(|(warehouse, moves)| format!("{warehouse}\n{moves}"))
+            .boxed(),
+        (2024, 16 | 20) => grid_with(".#", "SE"),
+        (2024, 17) => "Register A: [0-9]{1,6}\nRegister B: [0-9]{1,6}\nRegister C: [0-9]{1,6}\n\n\
+                    Program: [0-7],[0-7](,[0-7],[0-7]){0,7}\n"
+            .boxed(),
+        (2024, 18) => lines(r"(70|[1-6]?[0-9]),(70|[1-6]?[0-9])"),
+        (2024, 19) => {
+            "[wubrg]{1,3}(, [wubrg]{1,3}){0,6}\n\n[wubrg]{1,10}(\n[wubrg]{1,10}){0,5}\n".boxed()
+        }
+        (2024, 21) => lines(r"[0-9]{3}A"),
+        (2024, 22) => lines(r"[0-9]{1,8}"),
+        (2024, 23) => lines(r"[a-z]{2}-[a-z]{2}"),
+        (2024, 24) => (
+            lines(r"[xy][0-9]{2}: [01]"),
+            lines(r"[a-z0-9]{3} (AND|OR|XOR) [a-z0-9]{3} -> [a-z0-9]{3}"),
+        )
+            .prop_map(|(wires, gates)| format!("{wires}\n{gates}"))
+            .boxed(),
+        (2024, 25) => vec(vec("[#.]{5}", 7), 1..4)
+            .prop_map(|schematics| {
+                let schematics: Vec<String> =
+                    schematics.iter().map(|rows| rows.join("\n")).collect();
+                schematics.join("\n\n") + "\n"
+            })
+            .boxed(),
+        _ => return None,
+    };
+    Some(strategy)
+}
+
+/// A day together with well-formed input for it. Days without a grammar are left out here and
+/// reported by `test_every_day_has_a_grammar` instead.
+fn any_day_with_input() -> impl Strategy<Value = (PuzzleId, String)> {
+    let ids: Vec<PuzzleId> =
+        Day::ids().iter().copied().filter(|&id| valid_input(id).is_some()).collect();
+    select(ids).prop_flat_map(|id| (Just(id), valid_input(id).unwrap()))
+}
+
+/// One small edit of the kind that turns a valid input into a subtly broken one.
+#[derive(Debug, Clone)]
+enum Mutation {
+    Delete(Index),
+    Insert(Index, char),
+    Replace(Index, char),
+    Truncate(Index),
+    Repeat(Index, Index),
+}
+
+/// Characters that mean something to at least one day's grammar, plus anything at all.
+fn nasty_char() -> impl Strategy<Value = char> {
+    prop_oneof![
+        select("\n \t-+,:=|0123456789#.^<>vAXSEé".chars().collect::<Vec<char>>()),
+        any::<char>()
+    ]
+}
+
+fn mutation() -> impl Strategy<Value = Mutation> {
+    prop_oneof![
+        any::<Index>().prop_map(Mutation::Delete),
+        (any::<Index>(), nasty_char()).prop_map(|(i, c)| Mutation::Insert(i, c)),
+        (any::<Index>(), nasty_char()).prop_map(|(i, c)| Mutation::Replace(i, c)),
+        any::<Index>().prop_map(Mutation::Truncate),
+        (any::<Index>(), any::<Index>()).prop_map(|(i, j)| Mutation::Repeat(i, j)),
+    ]
+}
+
+impl Mutation {
+    fn apply(&self, input: &str) -> String {
+        let mut chars: Vec<char> = input.chars().collect();
+        if chars.is_empty() {
+            return String::new();
+        }
+        match *self {
+            Mutation::Delete(i) => {
+                chars.remove(i.index(chars.len()));
+            }
+            Mutation::Insert(i, c) => chars.insert(i.index(chars.len() + 1), c),
+            Mutation::Replace(i, c) => {
+                let i = i.index(chars.len());
+                chars[i] = c;
+            }
+            Mutation::Truncate(i) => chars.truncate(i.index(chars.len())),
+            Mutation::Repeat(i, j) => {
+                let (i, j) = (i.index(chars.len()), j.index(chars.len()));
+                let repeated: Vec<char> = chars[i.min(j)..=i.max(j)].to_vec();
+                chars.splice(i.max(j)..i.max(j), repeated);
+            }
+        }
+        chars.into_iter().collect()
+    }
+}
+
+#[test]
+fn test_every_day_has_a_grammar() {
+    let missing: Vec<PuzzleId> =
+        Day::ids().iter().copied().filter(|&id| valid_input(id).is_none()).collect();
+    assert!(missing.is_empty(), "no input grammar for {missing:?}");
+}
+
+proptest! {
+    #[test]
+    fn test_valid_input_parses((id, input) in any_day_with_input()) {
+        let checked = Day::from_id(id).unwrap().check_input(&input);
+        prop_assert!(checked.is_ok(), "{id} rejected {input:?}: {}", checked.unwrap_err());
+    }
+
+    #[test]
+    fn test_near_valid_input_never_panics(
+        (id, input) in any_day_with_input(),
+        mutations in vec(mutation(), 1..4),
+    ) {
+        let input = mutations.iter().fold(input, |input, mutation| mutation.apply(&input));
+        let _ = Day::from_id(id).unwrap().check_input(&input);
+    }
+
+    #[test]
+    fn test_arbitrary_input_never_panics(
+        id in select(Day::ids()),
+        input in prop_oneof![any::<String>(), "[0-9 ,:|=+\n#.A-Za-z-]{0,64}"],
+    ) {
+        let _ = Day::from_id(id).unwrap().check_input(&input);
+    }
+
+    #[test]
+    fn test_day1_round_trip(pairs in vec((any::<u32>(), any::<u32>()), 1..20)) {
+        let input: String = pairs.iter().map(|(left, right)| format!("{left}   {right}\n")).collect();
+        prop_assert_eq!(Day1::default().parse(&input).unwrap(), pairs);
+    }
+
+    #[test]
+    fn test_day7_round_trip(
+        tests in vec((any::<u64>(), vec(any::<u64>(), 1..8)), 1..10),
+    ) {
+        let input: String = tests
+            .iter()
+            .map(|(value, operands)| {
+                let operands: Vec<String> = operands.iter().map(u64::to_string).collect();
+                format!("{value}: {}\n", operands.join(" "))
+            })
+            .collect();
+        prop_assert_eq!(Day7::default().parse(&input).unwrap(), tests);
+    }
+
+    #[test]
+    fn test_day9_round_trip(disk_map in vec(0..10_usize, 1..100)) {
+        let input: String = disk_map.iter().map(usize::to_string).collect::<String>() + "\n";
+        prop_assert_eq!(Day9::default().parse(&input).unwrap(), disk_map);
+    }
+
+    #[test]
+    fn test_day11_round_trip(stones in vec(any::<u64>(), 1..10)) {
+        let input = stones.iter().map(u64::to_string).collect::<Vec<String>>().join(" ");
+        let parsed: Vec<u64> =
+            Day11::default().parse(&input).unwrap().into_iter().map(|(stone, _)| stone).collect();
+        prop_assert_eq!(parsed, stones);
+    }
+
+    #[test]
+    fn test_day18_round_trip(bytes in vec((0..71_usize, 0..71_usize), 1..50)) {
+        let input: String = bytes.iter().map(|(x, y)| format!("{x},{y}\n")).collect();
+        prop_assert_eq!(Day18::default().parse(&input).unwrap(), bytes);
+    }
+
+    #[test]
+    fn test_day19_round_trip(
+        patterns in hash_set("[wubrg]{1,4}", 1..8),
+        designs in vec("[wubrg]{1,12}", 1..8),
+    ) {
+        let available: Vec<&str> = patterns.iter().map(String::as_str).collect();
+        let input = format!("{}\n\n{}\n", available.join(", "), designs.join("\n"));
+        prop_assert_eq!(Day19::default().parse(&input).unwrap(), (patterns, designs));
+    }
+
+    #[test]
+    fn test_day23_round_trip(connections in hash_set(("[a-z]{2}", "[a-z]{2}"), 1..20)) {
+        let input: String = connections.iter().map(|(a, b)| format!("{a}-{b}\n")).collect();
+        // Each connection is stored with its computers in order, whichever way round it was listed.
+        let expected: HashSet<(String, String)> = connections
+            .into_iter()
+            .map(|(a, b)| if a < b { (a, b) } else { (b, a) })
+            .collect();
+        prop_assert_eq!(Day23::default().parse(&input).unwrap(), expected);
+    }
+}
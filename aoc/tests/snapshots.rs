@@ -0,0 +1,125 @@
+//! Every registered day runs against its fixture, `tests/fixtures/<year>/day<N>.txt`, and what it
+//! reports, apart from timings, has to match its snapshot, `tests/snapshots/<year>/day<N>.json`.
+//! A day whose example is smaller than its real input takes the options for it, such as
+//! `--size 7`, from `tests/fixtures/<year>/day<N>.args`.
+//! A day without a fixture fails, unless it is listed in [`WITHOUT_FIXTURE`]. Set `AOC_BLESS=1` to
+//! write the snapshots from the current output instead of checking them.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc::commands::{input_in, CommandImpl, Day, Parts, PuzzleId};
+use aoc::output::{DayReport, FailureReport};
+use serde_json::Value;
+
+/// Environment variable that re-blesses the snapshots when set to anything but `0`.
+const BLESS_ENV: &str = "AOC_BLESS";
+
+/// The year and day of every day that has no fixture yet. `cargo xtask new-day` adds each day it
+/// scaffolds; take the day out again once it has a fixture.
+const WITHOUT_FIXTURE: &[(u16, u8)] = &[];
+
+fn tests_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests")
+}
+
+fn snapshot_path(id: PuzzleId) -> PathBuf {
+    input_in(&tests_dir().join("snapshots"), id).with_extension("json")
+}
+
+/// The report `aoc --format json` writes for the day, without the timings that change from run
+/// to run, pretty-printed so that a changed answer shows up as a changed line.
+fn snapshot(id: PuzzleId, input: &str, args: &str) -> Result<String, Box<dyn std::error::Error>> {
+    let day = Day::with_args(id, args.split_whitespace())?;
+    let mut report = match day.run_parts(input, Parts::Both) {
+        Ok(run) => serde_json::to_value(DayReport::new(id, &run))?,
+        Err(error) => serde_json::to_value(FailureReport::new(id, &error.to_string()))?,
+    };
+    if let Value::Object(fields) = &mut report {
+        fields.retain(|key, _| !key.ends_with("_ms"));
+    }
+    Ok(serde_json::to_string_pretty(&report)? + "\n")
+}
+
+/// The lines that differ, as `-` for the snapshot and `+` for the output.
+fn diff(expected: &str, observed: &str) -> String {
+    let (expected, observed): (Vec<&str>, Vec<&str>) =
+        (expected.lines().collect(), observed.lines().collect());
+    let mut lines = Vec::new();
+    for i in 0..expected.len().max(observed.len()) {
+        let (e, o) = (expected.get(i), observed.get(i));
+        if e != o {
+            lines.extend(e.map(|e| format!("- {e}")));
+            lines.extend(o.map(|o| format!("+ {o}")));
+        }
+    }
+    lines.join("\n")
+}
+
+#[test]
+fn test_snapshots() -> Result<(), Box<dyn std::error::Error>> {
+    let bless = env::var_os(BLESS_ENV).is_some_and(|value| value != "0");
+    let mut failures = Vec::new();
+    for &id in Day::ids() {
+        let fixture = input_in(&tests_dir().join("fixtures"), id);
+        let allowed = WITHOUT_FIXTURE.contains(&(id.year, id.day));
+        let input = match fs::read_to_string(&fixture) {
+            Ok(_) if allowed => {
+                failures.push(format!("{id}: has a fixture, so drop it from WITHOUT_FIXTURE"));
+                continue;
+            }
+            Ok(input) => input,
+            Err(_) if allowed => continue,
+            Err(_) => {
+                failures.push(format!("{id}: no fixture at {}", fixture.display()));
+                continue;
+            }
+        };
+        let args = fs::read_to_string(fixture.with_extension("args")).unwrap_or_default();
+        let observed = snapshot(id, &input, &args)?;
+        let path = snapshot_path(id);
+        if bless {
+            fs::create_dir_all(path.parent().unwrap())?;
+            fs::write(&path, &observed)?;
+            continue;
+        }
+        match fs::read_to_string(&path) {
+            Ok(expected) if expected == observed => {}
+            Ok(expected) => failures.push(format!(
+                "{id}: output differs from {}\n{}",
+                path.display(),
+                diff(&expected, &observed)
+            )),
+            Err(_) => failures.push(format!("{id}: no snapshot at {}", path.display())),
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "{}\n\nIf the new output is right, run with {BLESS_ENV}=1 to accept it.",
+        failures.join("\n\n")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_every_snapshot_has_a_day() -> Result<(), Box<dyn std::error::Error>> {
+    let expected: Vec<PathBuf> = Day::ids().iter().map(|&id| snapshot_path(id)).collect();
+    let mut stale = Vec::new();
+    for year in fs::read_dir(tests_dir().join("snapshots"))? {
+        for entry in fs::read_dir(year?.path())? {
+            let path = entry?.path();
+            if !expected.contains(&path) {
+                stale.push(path);
+            }
+        }
+    }
+    assert!(stale.is_empty(), "snapshots of no registered day: {stale:?}");
+    Ok(())
+}
+
+#[test]
+fn test_diff() {
+    assert_eq!(diff("a\nb\nc\n", "a\nx\nc\nd\n"), "- b\n+ x\n+ d");
+    assert_eq!(diff("a\n", "a\n"), "");
+}
@@ -1,7 +1,6 @@
 use enum_dispatch::enum_dispatch;
-use std::env;
+use std::fs;
 use std::path::Path;
-use std::process::Command;
 use std::{error::Error, path::PathBuf};
 
 type DynError = Box<dyn Error>;
@@ -32,30 +31,171 @@ fn main() -> Result<(), DynError> {
 
 // -------------- Tasks -----------
 
+/// The module every new day starts from. `DayN` and `dayN` are replaced with the day's struct and
+/// module names, and `YEAR`, `DAY` and `TITLE` with its puzzle.
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs");
+
 #[derive(Parser, Debug)]
 struct NewDay {
-    /// Create a new day
+    /// Day of the puzzle, e.g. `8`
+    #[clap(long, short, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Title of the puzzle, e.g. `Resonant Collinearity`
     #[clap(long, short)]
-    name: String,
+    title: String,
+    /// Year of the puzzle
+    #[clap(long, short, default_value_t = 2024)]
+    year: u16,
+}
+
+/// Add `pub mod <name>;` to the module declarations of `mod_rs`, which are kept sorted after its
+/// doc comment.
+fn declare(mod_rs: &str, name: &str) -> Result<String, DynError> {
+    let declaration = format!("pub mod {name};");
+    if mod_rs.lines().any(|line| line == declaration) {
+        return Err(format!("{name} is already declared").into());
+    }
+
+    let mut lines: Vec<String> = mod_rs.lines().map(String::from).collect();
+    let first = lines.iter().position(|line| line.starts_with("pub mod ")).unwrap_or_else(|| {
+        lines.iter().take_while(|line| line.starts_with("//!") || line.is_empty()).count()
+    });
+    let modules =
+        lines[first..].iter().take_while(|line| line.starts_with("pub mod ")).count() + first;
+    lines.insert(modules, declaration);
+    lines[first..=modules].sort();
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// The module of a year with no days in it yet.
+fn year_module(year: u16) -> String {
+    format!("//! Solutions to the puzzles of Advent of Code {year}.\n\n")
+}
+
+/// The arm `valid_input` in `aoc/tests/parsers.rs` ends with, for days with no grammar.
+const NO_GRAMMAR: &str = "        _ => return None,";
+
+/// Add a grammar for `day` of `year` to `valid_input` in `parsers_rs`, matching the lines of
+/// letters the template parses, so that a new day passes the parser tests until it has its own.
+fn grammar(parsers_rs: &str, year: u16, day: u8) -> Result<String, DynError> {
+    let arm = format!("        ({year}, {day}) => ");
+    if parsers_rs.lines().any(|line| line.starts_with(&arm)) {
+        return Err(format!("{year}/day{day} already has a grammar").into());
+    }
+    let mut lines: Vec<String> = parsers_rs.lines().map(String::from).collect();
+    let last = lines
+        .iter()
+        .position(|line| line == NO_GRAMMAR)
+        .ok_or("no catch-all arm to add the grammar before")?;
+    lines.insert(last, format!("{arm}lines(r\"[a-z]{{1,20}}\"),"));
+    Ok(lines.join("\n") + "\n")
+}
+
+/// The declaration of the days `aoc/tests/snapshots.rs` runs without a fixture.
+const WITHOUT_FIXTURE: &str = "const WITHOUT_FIXTURE: &[(u16, u8)] = &[";
+
+/// Add `day` of `year` to the days without a fixture in `snapshots_rs`, so that a new day passes
+/// the snapshot tests until it has a fixture of its own.
+fn without_fixture(snapshots_rs: &str, year: u16, day: u8) -> Result<String, DynError> {
+    let mut lines: Vec<String> = snapshots_rs.lines().map(String::from).collect();
+    let first = lines
+        .iter()
+        .position(|line| line.starts_with(WITHOUT_FIXTURE))
+        .ok_or("no list of days without a fixture")?;
+    let last = (first..lines.len())
+        .find(|&i| lines[i].ends_with("];"))
+        .ok_or("the list of days without a fixture is not closed")?;
+
+    // The list holds nothing but pairs of numbers, so these are read off two at a time.
+    let list = lines[first..=last].join(" ");
+    let numbers: Vec<u16> = list[WITHOUT_FIXTURE.len()..]
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|number| !number.is_empty())
+        .map(str::parse)
+        .collect::<Result<_, _>>()?;
+    let mut days: Vec<(u16, u16)> = numbers.chunks(2).map(|pair| (pair[0], pair[1])).collect();
+    if days.contains(&(year, day.into())) {
+        return Err(format!("{year}/day{day} is already listed without a fixture").into());
+    }
+    days.push((year, day.into()));
+    days.sort();
+
+    // Laid out as rustfmt would: on one line when it fits, otherwise one day per line.
+    let days: Vec<String> = days.iter().map(|(year, day)| format!("({year}, {day})")).collect();
+    let line = format!("{WITHOUT_FIXTURE}{}];", days.join(", "));
+    let list = if line.len() <= 100 {
+        vec![line]
+    } else {
+        let mut list = vec![WITHOUT_FIXTURE.to_string()];
+        list.extend(days.iter().map(|day| format!("    {day},")));
+        list.push("];".to_string());
+        list
+    };
+    lines.splice(first..=last, list);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// The template filled in for `day` of the puzzles of `year`, titled `title`.
+fn render(year: u16, day: u8, title: &str) -> String {
+    DAY_TEMPLATE
+        .replace("DayN", &format!("Day{day}"))
+        .replace("dayN", &format!("day{day}"))
+        .replace("YEAR", &year.to_string())
+        .replace("DAY", &day.to_string())
+        .replace("TITLE", &format!("{title:?}"))
 }
 
 impl CommandImpl for NewDay {
+    /// Work out every file the new day needs before writing any, so that a step that fails
+    /// leaves the tree as it was.
     fn main(&self) -> Result<(), DynError> {
-        let template = project_root().join("aoc").join("src").join("commands").join("day0.rs");
-        let dest = project_root()
-            .join("aoc")
-            .join("src")
-            .join("commands")
-            .join(format!("{}.rs", self.name));
-
-        let status = Command::new("cp")
-            .current_dir(project_root())
-            .args([template.to_str().unwrap(), dest.to_str().unwrap()])
-            .status()?;
-        if !status.success() {
-            return Err("cargo install failed".into());
+        let name = format!("day{}", self.day);
+        let commands = project_root().join("aoc").join("src").join("commands");
+        let year_dir = commands.join(format!("y{}", self.year));
+        let dest = year_dir.join(format!("{name}.rs"));
+        if dest.exists() {
+            return Err(
+                format!("{} already exists, refusing to overwrite it", dest.display()).into()
+            );
+        }
+
+        let mut writes: Vec<(PathBuf, String)> = Vec::new();
+        // A year's first day also brings in the module for the year.
+        let year_rs = year_dir.join("mod.rs");
+        let year_mod = if year_rs.exists() {
+            fs::read_to_string(&year_rs)?
+        } else {
+            let commands_rs = commands.join("mod.rs");
+            let declared = declare(&fs::read_to_string(&commands_rs)?, &format!("y{}", self.year))?;
+            writes.push((commands_rs, declared));
+            year_module(self.year)
+        };
+        writes.push((year_rs, declare(&year_mod, &name)?));
+        writes.push((dest, render(self.year, self.day, &self.title)));
+
+        let parsers_rs = project_root().join("aoc").join("tests").join("parsers.rs");
+        let with_grammar = grammar(&fs::read_to_string(&parsers_rs)?, self.year, self.day)?;
+        writes.push((parsers_rs, with_grammar));
+
+        let snapshots_rs = project_root().join("aoc").join("tests").join("snapshots.rs");
+        let listed = without_fixture(&fs::read_to_string(&snapshots_rs)?, self.year, self.day)?;
+        writes.push((snapshots_rs, listed));
+
+        let inputs = project_root().join("aoc").join("inputs").join(self.year.to_string());
+        let input = inputs.join(format!("{name}.txt"));
+        if !input.exists() {
+            writes.push((input, String::new()));
         }
 
+        for (path, contents) in writes {
+            let existed = path.exists();
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(&path, contents)?;
+            println!("{} {}", if existed { "updated" } else { "created" }, path.display());
+        }
         Ok(())
     }
 }
@@ -63,3 +203,74 @@ impl CommandImpl for NewDay {
 fn project_root() -> PathBuf {
     Path::new(&env!("CARGO_MANIFEST_DIR")).ancestors().nth(1).unwrap().to_path_buf()
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_declare() -> Result<(), Box<dyn std::error::Error>> {
+        let mod_rs = "pub mod day1;\npub mod day9;\n\nuse std::fmt;\n";
+        let expected = "pub mod day1;\npub mod day8;\npub mod day9;\n\nuse std::fmt;\n";
+        assert_eq!(declare(mod_rs, "day8")?, expected);
+        assert!(declare(expected, "day8").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_declare_after_doc_comment() -> Result<(), Box<dyn std::error::Error>> {
+        let year_rs = year_module(2023);
+        let declared = declare(&year_rs, "day8")?;
+        assert_eq!(
+            declared,
+            "//! Solutions to the puzzles of Advent of Code 2023.\n\npub mod day8;\n"
+        );
+        let expected = "//! Solutions to the puzzles of Advent of Code 2023.\n\npub mod day12;\npub mod day8;\n";
+        assert_eq!(declare(&declared, "day12")?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_grammar() -> Result<(), Box<dyn std::error::Error>> {
+        let parsers_rs = "    match (id.year, id.day) {\n        (2024, 1) => lines(r\"[0-9]\"),\n        _ => return None,\n    };\n";
+        let expected = "    match (id.year, id.day) {\n        (2024, 1) => lines(r\"[0-9]\"),\n        (2023, 8) => lines(r\"[a-z]{1,20}\"),\n        _ => return None,\n    };\n";
+        assert_eq!(grammar(parsers_rs, 2023, 8)?, expected);
+        assert!(grammar(expected, 2023, 8).is_err());
+        assert!(grammar("fn main() {}\n", 2023, 8).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_without_fixture() -> Result<(), Box<dyn std::error::Error>> {
+        let snapshots_rs = "const A: u8 = 1;\nconst WITHOUT_FIXTURE: &[(u16, u8)] = &[];\n";
+        let listed = without_fixture(snapshots_rs, 2024, 8)?;
+        assert_eq!(
+            listed,
+            "const A: u8 = 1;\nconst WITHOUT_FIXTURE: &[(u16, u8)] = &[(2024, 8)];\n"
+        );
+        let expected =
+            "const A: u8 = 1;\nconst WITHOUT_FIXTURE: &[(u16, u8)] = &[(2023, 12), (2024, 8)];\n";
+        assert_eq!(without_fixture(&listed, 2023, 12)?, expected);
+        assert!(without_fixture(expected, 2024, 8).is_err());
+        assert!(without_fixture("fn main() {}\n", 2024, 8).is_err());
+
+        let mut long = snapshots_rs.to_string();
+        for day in 1..=10 {
+            long = without_fixture(&long, 2024, day)?;
+        }
+        assert!(long.contains("&[\n    (2024, 1),\n    (2024, 2),\n"), "{long}");
+        assert!(long.ends_with("    (2024, 10),\n];\n"), "{long}");
+        assert!(without_fixture(&long, 2024, 11)?.contains("    (2024, 11),\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_render() {
+        let day = render(2023, 12, "Hot \"Springs\"");
+        assert!(day.contains("pub struct Day12 {"));
+        assert!(day.contains("Registration::new::<Day12>(Puzzle {"));
+        assert!(day.contains("year: 2023,\n        day: 12,\n"));
+        assert!(day.contains("title: \"Hot \\\"Springs\\\"\","));
+        assert!(day.contains("input: \"day12\","));
+    }
+}
@@ -0,0 +1,76 @@
+use clap::Parser;
+use nom::{
+    character::complete::{line_ending, not_line_ending},
+    multi::separated_list1,
+    IResult,
+};
+use rand::Rng;
+
+use crate::commands::{DayArgs, DynError, Parts, Puzzle, Registration, Solution, Solver};
+use crate::utils::from_nom;
+use crate::utils::random::InputRng;
+
+#[derive(Parser, Debug, Default)]
+pub struct DayN {
+    #[clap(flatten)]
+    args: DayArgs,
+}
+
+inventory::submit! {
+    Registration::new::<DayN>(Puzzle {
+        year: YEAR,
+        day: DAY,
+        title: TITLE,
+        parts: Parts::Both,
+        input: "dayN",
+    })
+}
+
+fn parse_lines(input: &str) -> IResult<&str, Vec<String>> {
+    let (input, lines) = separated_list1(line_ending, not_line_ending)(input)?;
+    Ok((input, lines.into_iter().map(String::from).collect()))
+}
+
+impl Solver for DayN {
+    type Parsed = Vec<String>;
+
+    fn args(&self) -> &DayArgs {
+        &self.args
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, DynError> {
+        Ok(from_nom(input, parse_lines(input))?)
+    }
+
+    fn part1(&self, _lines: &Self::Parsed) -> Result<Solution, DynError> {
+        Ok(Solution::new())
+    }
+
+    fn part2(&self, _lines: &Self::Parsed) -> Result<Solution, DynError> {
+        Ok(Solution::new())
+    }
+
+    fn generate(&self, rng: &mut InputRng, size: Option<usize>) -> Option<String> {
+        let lines: Vec<String> = (0..size.unwrap_or(1000).max(1))
+            .map(|_| (0..rng.gen_range(1..20)).map(|_| rng.gen_range('a'..='z')).collect())
+            .collect();
+        Some(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case("first\nsecond", 2usize)]
+    fn test_parse_lines(
+        #[case] input: &'static str,
+        #[case] expected: usize,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let (_, lines) = parse_lines(input)?;
+        assert_eq!(lines.len(), expected);
+        Ok(())
+    }
+}